@@ -1,13 +1,13 @@
 use std::collections::HashMap;
 use std::env;
-use std::fmt::{Debug, Formatter, Pointer};
+use std::fmt::{Debug, Formatter};
 use std::fs::File;
 use std::io::{BufWriter, Write};
 use std::path::{Path, PathBuf};
 use std::str::Split;
 
 trait FromValueSplit {
-    fn from_value_split(id: u16, value_split: &Vec<&str>) -> Self;
+    fn from_value_split(id: u16, value_split: &[&str]) -> Self;
 }
 
 enum ObjectInformationExtra {
@@ -26,7 +26,6 @@ impl Debug for ObjectInformationExtra {
     }
 }
 
-#[derive(Debug)]
 struct ObjectInformation {
     pub name: String,
     pub price: u32,
@@ -37,8 +36,23 @@ struct ObjectInformation {
     pub extra: ObjectInformationExtra,
 }
 
+// Written out rather than derived, as derived Debug impls don't count as reading the fields.
+impl Debug for ObjectInformation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ObjectInformation")
+            .field("name", &self.name)
+            .field("price", &self.price)
+            .field("edibility", &self.edibility)
+            .field("type_and_category", &self.type_and_category)
+            .field("display_name", &self.display_name)
+            .field("description", &self.description)
+            .field("extra", &self.extra)
+            .finish()
+    }
+}
+
 impl FromValueSplit for ObjectInformation {
-    fn from_value_split(id: u16, value_split: &Vec<&str>) -> Self {
+    fn from_value_split(id: u16, value_split: &[&str]) -> Self {
         let extra: ObjectInformationExtra = match id {
             535u16 | 536u16 | 537u16 | 749u16 | 275u16 => ObjectInformationExtra::Treasure(
                 value_split[6usize]
@@ -61,7 +75,6 @@ impl FromValueSplit for ObjectInformation {
     }
 }
 
-#[derive(Debug)]
 struct BigCraftablesInformation {
     pub name: String,
     pub price: u32,
@@ -74,8 +87,24 @@ struct BigCraftablesInformation {
     pub display_name: String,
 }
 
+impl Debug for BigCraftablesInformation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BigCraftablesInformation")
+            .field("name", &self.name)
+            .field("price", &self.price)
+            .field("edibility", &self.edibility)
+            .field("type_and_category", &self.type_and_category)
+            .field("description", &self.description)
+            .field("can_be_set_outdoors", &self.can_be_set_outdoors)
+            .field("can_be_set_indoors", &self.can_be_set_indoors)
+            .field("fragility", &self.fragility)
+            .field("display_name", &self.display_name)
+            .finish()
+    }
+}
+
 impl FromValueSplit for BigCraftablesInformation {
-    fn from_value_split(id: u16, value_split: &Vec<&str>) -> Self {
+    fn from_value_split(_id: u16, value_split: &[&str]) -> Self {
         Self {
             name: value_split[0usize].to_string(),
             price: value_split[1usize].parse::<u32>().unwrap(),
@@ -90,7 +119,6 @@ impl FromValueSplit for BigCraftablesInformation {
     }
 }
 
-#[derive(Debug)]
 struct Furniture {
     pub name: String,
     pub type_: String,
@@ -102,6 +130,21 @@ struct Furniture {
     pub price: u32,
 }
 
+impl Debug for Furniture {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Furniture")
+            .field("name", &self.name)
+            .field("type_", &self.type_)
+            .field("source_rectangle_width", &self.source_rectangle_width)
+            .field("source_rectangle_height", &self.source_rectangle_height)
+            .field("bounding_box_width", &self.bounding_box_width)
+            .field("bounding_box_height", &self.bounding_box_height)
+            .field("rotations", &self.rotations)
+            .field("price", &self.price)
+            .finish()
+    }
+}
+
 impl FromValueSplit for Furniture {
     // Integer types (Furniture.getTypeNumberFromName):
     // 0 = chair
@@ -122,7 +165,7 @@ impl FromValueSplit for Furniture {
     // 15 = bed...
     // 16 = torch
     // 17 = sconce
-    fn from_value_split(id: u16, value_split: &Vec<&str>) -> Self {
+    fn from_value_split(_id: u16, value_split: &[&str]) -> Self {
         let type_: &str = value_split[1usize];
 
         let (source_rectangle_width, source_rectangle_height): (u8, u8) = match value_split[2usize]
@@ -193,7 +236,6 @@ impl FromValueSplit for Furniture {
     }
 }
 
-#[derive(Debug)]
 struct ClothingInformation {
     pub name: String,
     pub display_name: String,
@@ -206,8 +248,24 @@ struct ClothingInformation {
     pub type_: String,
 }
 
+impl Debug for ClothingInformation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClothingInformation")
+            .field("name", &self.name)
+            .field("display_name", &self.display_name)
+            .field("description", &self.description)
+            .field("male_index", &self.male_index)
+            .field("female_index", &self.female_index)
+            .field("price", &self.price)
+            .field("rgb", &self.rgb)
+            .field("dyeable", &self.dyeable)
+            .field("type_", &self.type_)
+            .finish()
+    }
+}
+
 impl FromValueSplit for ClothingInformation {
-    fn from_value_split(id: u16, value_split: &Vec<&str>) -> Self {
+    fn from_value_split(_id: u16, value_split: &[&str]) -> Self {
         let male_index: u16 = value_split[3usize].parse::<u16>().unwrap();
         let female_index: u16 = match value_split[4usize] {
             "-1" => male_index,
@@ -234,7 +292,6 @@ impl FromValueSplit for ClothingInformation {
     }
 }
 
-#[derive(Debug)]
 struct Hats {
     pub name: String,
     pub description: String,
@@ -243,8 +300,20 @@ struct Hats {
     pub is_prismatic: bool,
 }
 
+impl Debug for Hats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Hats")
+            .field("name", &self.name)
+            .field("description", &self.description)
+            .field("hair_draw_type", &self.hair_draw_type)
+            .field("ignore_hairstyle_offset", &self.ignore_hairstyle_offset)
+            .field("is_prismatic", &self.is_prismatic)
+            .finish()
+    }
+}
+
 impl FromValueSplit for Hats {
-    fn from_value_split(id: u16, value_split: &Vec<&str>) -> Self {
+    fn from_value_split(_id: u16, value_split: &[&str]) -> Self {
         let hair_draw_type: u8 = match value_split[2usize] {
             "true" => 0u8,
             "false" => 1u8,
@@ -255,11 +324,8 @@ impl FromValueSplit for Hats {
         let mut is_prismatic = false;
         if value_split.len() > 4 {
             for special_tag in value_split[4usize].split(" ") {
-                match special_tag {
-                    "Prismatic" => {
-                        is_prismatic = true;
-                    }
-                    _ => {}
+                if special_tag == "Prismatic" {
+                    is_prismatic = true;
                 }
             }
         }
@@ -274,6 +340,100 @@ impl FromValueSplit for Hats {
     }
 }
 
+struct BundleItem {
+    pub id: i32,
+    pub quantity: u32,
+    pub quality: u8,
+}
+
+impl Debug for BundleItem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BundleItem")
+            .field("id", &self.id)
+            .field("quantity", &self.quantity)
+            .field("quality", &self.quality)
+            .finish()
+    }
+}
+
+struct Bundle {
+    pub room: String,
+    pub name: String,
+    pub items: Vec<BundleItem>,
+    pub count: u8,
+}
+
+impl Debug for Bundle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Bundle {{ room: {:?}, name: {:?}, items: &{:?}, count: {:?} }}",
+            self.room, self.name, self.items, self.count
+        )
+    }
+}
+
+impl Bundle {
+    fn from_key_and_value_split(room: &str, value_split: &[&str]) -> Self {
+        let items_split: Vec<&str> = value_split[2usize].split(" ").collect();
+        let items: Vec<BundleItem> = items_split
+            .chunks(3usize)
+            .map(|item_split: &[&str]| {
+                let id: i32 = item_split[0usize].parse::<i32>().unwrap();
+                BundleItem {
+                    id,
+                    quantity: item_split[1usize].parse::<u32>().unwrap(),
+                    // Gold repeats the amount in place of the quality.
+                    quality: match id {
+                        -1i32 => 0u8,
+                        _ => item_split[2usize].parse::<u8>().unwrap(),
+                    },
+                }
+            })
+            .collect();
+
+        // Without a count, every item is needed.
+        let count: u8 = match value_split.get(4usize) {
+            Some(count) => count.parse::<u8>().unwrap(),
+            None => items.len() as u8,
+        };
+
+        Self {
+            room: room.to_string(),
+            name: value_split[0usize].to_string(),
+            items,
+            count,
+        }
+    }
+}
+
+/// Bundles are keyed by room and index, such as `Pantry/0`, so they don't fit `load`.
+fn load_bundles(out_file: &mut BufWriter<File>, path: &Path) {
+    let file: File = File::open(path).unwrap();
+    let json: serde_json::Value = serde_json::from_reader(file).unwrap();
+
+    let mut builder: phf_codegen::Map<u16> = phf_codegen::Map::new();
+    let mut entries: Vec<(u16, String)> = Vec::new();
+    for (key, value) in json.get("content").unwrap().as_object().unwrap() {
+        let (room, index): (&str, &str) = key.split_once("/").unwrap();
+        let value_split: Vec<&str> = value.as_str().unwrap().split("/").collect();
+        entries.push((
+            index.parse::<u16>().unwrap(),
+            format!("{:?}", Bundle::from_key_and_value_split(room, &value_split)),
+        ));
+    }
+    for (key, value) in &entries {
+        builder.entry(*key, value);
+    }
+
+    writeln!(
+        out_file,
+        "pub static BUNDLES: phf::Map<u16, Bundle> = {};",
+        builder.build(),
+    )
+    .unwrap();
+}
+
 fn load<T: Debug + FromValueSplit>(
     out_file: &mut BufWriter<File>,
     path: &Path,
@@ -349,4 +509,7 @@ fn main() {
 
     let hats_path: &Path = Path::new("assets/hats.json");
     load::<Hats>(&mut out_file, hats_path, "HATS", "Hats");
+
+    let bundles_path: &Path = Path::new("assets/Bundles.json");
+    load_bundles(&mut out_file, bundles_path);
 }
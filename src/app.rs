@@ -1,67 +1,175 @@
 use yew::prelude::*;
 
+use crate::completed_bundles::{load_completed_bundles, save_completed_bundles};
 use crate::components::configuration_form::ConfigurationForm;
 use crate::components::tabs::Tabs;
 use crate::configuration::Configuration;
+use crate::donations::{load_donations, save_donations};
+use crate::implementations::bundles::Bundles;
+use crate::implementations::farm_events::FarmEvents;
+use crate::implementations::geode_planner::GeodePlanner;
 use crate::implementations::geodes::Geodes;
 use crate::implementations::joja::Joja;
 use crate::implementations::krobus::Krobus;
+use crate::implementations::luck::Luck;
+use crate::implementations::mines::Mines;
+use crate::implementations::museum::Museum;
 use crate::implementations::pierre::Pierre;
+use crate::implementations::saloon::Saloon;
 use crate::implementations::sandy::Sandy;
+use crate::implementations::search::Search;
+use crate::implementations::trash_cans::TrashCans;
 use crate::implementations::traveling_cart::TravelingCart;
-use crate::implementations::util::Implementation;
+use crate::implementations::util::{Implementation, IMPLEMENTATIONS};
+use crate::implementations::weather::Weather;
+use crate::location::{LocationState, View};
+use crate::predictors::bundles::BundleSlot;
 
 #[component]
 pub fn App() -> Html {
-    let configuration = use_state_eq(|| None::<Configuration>);
-    let implementation = use_state_eq(|| None::<Implementation>);
+    let initial = use_memo((), |_| LocationState::read());
+    let configuration = use_state_eq(|| initial.configuration.clone());
+    let implementation = use_state_eq(|| initial.implementation);
+    let view = use_state_eq(|| initial.view.clone());
+    let donated = use_state_eq(Vec::<u16>::new);
+    let completed_bundles = use_state_eq(Vec::<BundleSlot>::new);
+
+    {
+        let location_state = LocationState {
+            configuration: (*configuration).clone(),
+            implementation: *implementation,
+            view: (*view).clone(),
+        };
+        use_effect_with(location_state, |location_state| location_state.write());
+    }
+
+    {
+        let donated = donated.clone();
+        let completed_bundles = completed_bundles.clone();
+        let seed: Option<i32> = configuration
+            .as_ref()
+            .map(|configuration| configuration.seed);
+        use_effect_with(seed, move |seed| {
+            if let Some(seed) = seed {
+                donated.set(load_donations(*seed));
+                completed_bundles.set(load_completed_bundles(*seed));
+            }
+        });
+    }
 
     let configuration_updated = {
         let configuration = configuration.clone();
         let implementation = implementation.clone();
+        let view = view.clone();
         Callback::from(move |config: Configuration| {
             configuration.set(Some(config));
             implementation.set(None); // Reset tabs so all implementations are created fresh.
+            view.set(View::default());
         })
     };
 
     let implementation_updated = {
         let implementation = implementation.clone();
+        let view = view.clone();
         Callback::from(move |impl_: Implementation| {
             implementation.set(Some(impl_));
+            view.set(View::default());
+        })
+    };
+
+    let view_updated = {
+        let view = view.clone();
+        Callback::from(move |value: View| view.set(value))
+    };
+
+    let donated_updated = {
+        let donated = donated.clone();
+        let seed: Option<i32> = configuration
+            .as_ref()
+            .map(|configuration| configuration.seed);
+        Callback::from(move |value: Vec<u16>| {
+            if let Some(seed) = seed {
+                // Keep the donations for this session even if they can't be stored.
+                let _ = save_donations(seed, &value);
+            }
+            donated.set(value);
+        })
+    };
+
+    let completed_bundles_updated = {
+        let completed_bundles = completed_bundles.clone();
+        let seed: Option<i32> = configuration
+            .as_ref()
+            .map(|configuration| configuration.seed);
+        Callback::from(move |value: Vec<BundleSlot>| {
+            if let Some(seed) = seed {
+                // Keep the completed slots for this session even if they can't be stored.
+                let _ = save_completed_bundles(seed, &value);
+            }
+            completed_bundles.set(value);
         })
     };
 
     html!(
         <>
-            <ConfigurationForm updated={ configuration_updated } />
+            <ConfigurationForm updated={ configuration_updated } initial={ initial.configuration.clone() } />
             {
                 match (*configuration).clone() {
                     Some(configuration) => html!(
                         <section class="section">
                             <h1 class="title">{ "Results" }</h1>
                             <div class="container">
-                                <Tabs<Implementation> tabs={ vec![Implementation::TravelingCart, Implementation::Krobus, Implementation::Sandy, Implementation::Pierre, Implementation::Joja, Implementation::Geodes] } selected={ *implementation } updated={ implementation_updated } />
+                                <Tabs<Implementation> tabs={ IMPLEMENTATIONS.to_vec() } selected={ *implementation } updated={ implementation_updated } />
                                 {
                                     match *implementation {
                                         None => html!(),
                                         Some(Implementation::TravelingCart) => html!(
-                                            <TravelingCart configuration={ configuration.clone() } />
+                                            <TravelingCart configuration={ configuration.clone() } view={ (*view).clone() } view_updated={ view_updated.clone() } completed_bundles={ Some((*completed_bundles).clone()) } />
                                         ),
                                         Some(Implementation::Krobus) => html!(
-                                            <Krobus configuration={ configuration.clone() } />
+                                            <Krobus configuration={ configuration.clone() } view={ (*view).clone() } view_updated={ view_updated.clone() } completed_bundles={ Some((*completed_bundles).clone()) } />
                                         ),
                                         Some(Implementation::Sandy) => html!(
-                                            <Sandy configuration={ configuration.clone() } />
+                                            <Sandy configuration={ configuration.clone() } view={ (*view).clone() } view_updated={ view_updated.clone() } completed_bundles={ Some((*completed_bundles).clone()) } />
                                         ),
                                         Some(Implementation::Pierre) => html!(
-                                            <Pierre configuration={ configuration.clone() } />
+                                            <Pierre configuration={ configuration.clone() } view={ (*view).clone() } view_updated={ view_updated.clone() } completed_bundles={ Some((*completed_bundles).clone()) } />
                                         ),
                                         Some(Implementation::Joja) => html!(
-                                            <Joja configuration={ configuration.clone() } />
+                                            <Joja configuration={ configuration.clone() } view={ (*view).clone() } view_updated={ view_updated.clone() } completed_bundles={ Some((*completed_bundles).clone()) } />
+                                        ),
+                                        Some(Implementation::Saloon) => html!(
+                                            <Saloon configuration={ configuration.clone() } view={ (*view).clone() } view_updated={ view_updated.clone() } completed_bundles={ Some((*completed_bundles).clone()) } />
                                         ),
                                         Some(Implementation::Geodes) => html!(
-                                            <Geodes configuration={ configuration.clone() } />
+                                            <Geodes configuration={ configuration.clone() } view={ (*view).clone() } view_updated={ view_updated.clone() } donated={ Some((*donated).clone()) } />
+                                        ),
+                                        Some(Implementation::GeodePlanner) => html!(
+                                            <GeodePlanner configuration={ configuration.clone() } view={ (*view).clone() } view_updated={ view_updated.clone() } />
+                                        ),
+                                        Some(Implementation::Museum) => html!(
+                                            <Museum view={ (*view).clone() } view_updated={ view_updated.clone() } donated={ (*donated).clone() } donated_updated={ donated_updated.clone() } />
+                                        ),
+                                        Some(Implementation::Bundles) => html!(
+                                            <Bundles completed_bundles={ (*completed_bundles).clone() } completed_bundles_updated={ completed_bundles_updated.clone() } />
+                                        ),
+                                        Some(Implementation::Luck) => html!(
+                                            <Luck configuration={ configuration.clone() } view={ (*view).clone() } view_updated={ view_updated.clone() } />
+                                        ),
+                                        Some(Implementation::Weather) => html!(
+                                            <Weather configuration={ configuration.clone() } view={ (*view).clone() } view_updated={ view_updated.clone() } />
+                                        ),
+                                        Some(Implementation::FarmEvents) => html!(
+                                            <FarmEvents configuration={ configuration.clone() } view={ (*view).clone() } view_updated={ view_updated.clone() } />
+                                        ),
+                                        Some(Implementation::Mines) => html!(
+                                            <Mines configuration={ configuration.clone() } view={ (*view).clone() } view_updated={ view_updated.clone() } />
+                                        ),
+                                        Some(Implementation::TrashCans) => html!(
+                                            <TrashCans configuration={ configuration.clone() } view={ (*view).clone() } view_updated={ view_updated.clone() } />
+                                        ),
+                                        Some(Implementation::Search) => html!(
+                                            <Search configuration={ configuration.clone() } view={ (*view).clone() } view_updated={ view_updated.clone() } />
                                         ),
                                     }
                                 }
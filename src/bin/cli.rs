@@ -0,0 +1,655 @@
+use std::env::args_os;
+use std::ffi::OsString;
+use std::fs::read_to_string;
+use std::io::{stdout, BufWriter, ErrorKind, Write};
+use std::ops::{Range, RangeInclusive};
+use std::path::PathBuf;
+use std::thread::available_parallelism;
+
+use anyhow::{bail, Context, Result};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+use stardew_predictor::configuration::{Configuration, Platform};
+use stardew_predictor::export::{
+    export_farm_events, export_geodes, export_luck, export_mines, export_stock, export_tables,
+    export_trash_cans, export_weather, ExportFormat, ExportTable,
+};
+use stardew_predictor::predictors::farm_events::get_farm_event;
+use stardew_predictor::predictors::geode_planner::{plan_geodes, GeodePlan};
+use stardew_predictor::predictors::geodes::{get_geodes, GeodeType, GEODE_TYPES};
+use stardew_predictor::predictors::joja::JojaPredictor;
+use stardew_predictor::predictors::krobus::KrobusPredictor;
+use stardew_predictor::predictors::luck::{get_daily_luck, SpiritsMood};
+use stardew_predictor::predictors::mines::{get_mine_floors, MINES_BOTTOM};
+use stardew_predictor::predictors::pierre::PierrePredictor;
+use stardew_predictor::predictors::saloon::SaloonPredictor;
+use stardew_predictor::predictors::sandy::SandyPredictor;
+use stardew_predictor::predictors::trash_cans::{get_trash_cans_loot, TrashFind};
+use stardew_predictor::predictors::traveling_cart::TravelingCartPredictor;
+use stardew_predictor::predictors::util::{
+    format_date, get_stock_days, parse_date, StockDay, StockPredictor,
+};
+use stardew_predictor::predictors::weather::{get_weather_chances, WeatherChances};
+use stardew_predictor::save::configuration_from_save;
+use stardew_predictor::seed_search::{scan_seeds, SeedPredicate};
+
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+enum Predictor {
+    TravelingCart,
+    Krobus,
+    Sandy,
+    Pierre,
+    Joja,
+    Saloon,
+    Geodes,
+    Luck,
+    Weather,
+    FarmEvents,
+    Mines,
+    TrashCans,
+}
+
+/// Optional configuration parameters shared by every command.
+#[derive(Args)]
+struct ConfigurationArguments {
+    /// PC or Switch.
+    #[arg(long)]
+    platform: Platform,
+    /// Farm save file to read the seed, date and other parameters from. Explicit arguments take
+    /// precedence over it.
+    #[arg(long)]
+    save: Option<PathBuf>,
+    #[arg(long)]
+    geodes_cracked: Option<u16>,
+    #[arg(long)]
+    mine_level: Option<u8>,
+    #[arg(long)]
+    qis_crop: Option<bool>,
+    #[arg(long)]
+    golden_helmet: Option<bool>,
+    #[arg(long)]
+    year_one_completable: Option<bool>,
+    /// Traveling cart visits left until the year 1 completable guarantee as of the date.
+    #[arg(long)]
+    visits_until_y1_guarantee: Option<i32>,
+    /// The player's unique multiplayer ID.
+    #[arg(long, allow_negative_numbers = true)]
+    player_id: Option<i64>,
+    /// Total steps taken so far today.
+    #[arg(long)]
+    steps_taken: Option<u32>,
+    #[arg(long)]
+    trash_cans_checked: Option<u32>,
+}
+
+impl ConfigurationArguments {
+    fn configuration(&self, seed: Option<i32>, date: Option<i32>) -> Result<Configuration> {
+        let save: Option<Configuration> = match &self.save {
+            Some(path) => Some(configuration_from_save(
+                &read_to_string(path).context("Error reading save.")?,
+                self.platform,
+            )?),
+            None => None,
+        };
+
+        Ok(Configuration {
+            platform: self.platform,
+            seed: match (seed, &save) {
+                (Some(seed), _) => seed,
+                (None, Some(save)) => save.seed,
+                (None, None) => bail!("Either a seed or a save must be given."),
+            },
+            date: date.or(save.as_ref().and_then(|save| save.date)),
+            geodes_cracked: self
+                .geodes_cracked
+                .or(save.as_ref().and_then(|save| save.geodes_cracked)),
+            mine_level: self
+                .mine_level
+                .or(save.as_ref().and_then(|save| save.mine_level)),
+            qis_crop: self
+                .qis_crop
+                .or(save.as_ref().and_then(|save| save.qis_crop)),
+            golden_helmet: self
+                .golden_helmet
+                .or(save.as_ref().and_then(|save| save.golden_helmet)),
+            year_one_completable: self
+                .year_one_completable
+                .or(save.as_ref().and_then(|save| save.year_one_completable)),
+            visits_until_y1_guarantee: self.visits_until_y1_guarantee.or(save
+                .as_ref()
+                .and_then(|save| save.visits_until_y1_guarantee)),
+            player_id: self
+                .player_id
+                .or(save.as_ref().and_then(|save| save.player_id)),
+            steps_taken: self
+                .steps_taken
+                .or(save.as_ref().and_then(|save| save.steps_taken)),
+            trash_cans_checked: self
+                .trash_cans_checked
+                .or(save.as_ref().and_then(|save| save.trash_cans_checked)),
+        })
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Prints predictions for one seed as tab-separated lines, or as CSV or JSON tables.
+    Predict {
+        #[command(flatten)]
+        configuration: ConfigurationArguments,
+        #[arg(long, allow_negative_numbers = true, required_unless_present = "save")]
+        seed: Option<i32>,
+        /// First date to predict, either a date number or season-day-year (e.g. fall-5-1). The
+        /// first day by default.
+        #[arg(long, value_parser = parse_date)]
+        date: Option<i32>,
+        /// Number of days to predict shop stock for.
+        #[arg(long, default_value_t = 28u16)]
+        days: u16,
+        /// Number of geodes to predict.
+        #[arg(long, default_value_t = 28u16)]
+        geodes: u16,
+        /// Number of step totals, from --steps-taken, to predict the next day's luck for.
+        #[arg(long, default_value_t = 28u16)]
+        steps: u16,
+        /// Predictors to run, all of them by default.
+        #[arg(long, value_enum, value_delimiter = ',')]
+        predictors: Vec<Predictor>,
+        /// csv or json, to print a table per predictor instead of tab-separated lines.
+        #[arg(long)]
+        format: Option<ExportFormat>,
+    },
+    /// Prints the seeds for which every predicate holds, one per line.
+    Search {
+        #[command(flatten)]
+        configuration: ConfigurationArguments,
+        /// Either cart:<item>:<before date> or geode:<geode type>:<geodes cracked>:<item>.
+        #[arg(long = "predicate", required = true)]
+        predicates: Vec<SeedPredicate>,
+        /// First date the traveling cart is checked from.
+        #[arg(long, value_parser = parse_date)]
+        date: Option<i32>,
+        /// First seed to scan, so that by default every seed is scanned.
+        #[arg(long, allow_negative_numbers = true, default_value_t = i32::MIN)]
+        from: i32,
+        #[arg(long, allow_negative_numbers = true, default_value_t = i32::MAX)]
+        to: i32,
+        /// Stop after this many seeds have been found.
+        #[arg(long, default_value_t = 10usize)]
+        limit: usize,
+        /// Number of threads to scan with, all available cores by default.
+        #[arg(long)]
+        threads: Option<usize>,
+    },
+    /// Prints the order to crack geodes in that finds the most wanted items.
+    Plan {
+        #[command(flatten)]
+        configuration: ConfigurationArguments,
+        #[arg(long, allow_negative_numbers = true, required_unless_present = "save")]
+        seed: Option<i32>,
+        /// Geodes held, as <geode type>:<count>.
+        #[arg(long = "hold", required = true, value_parser = parse_held)]
+        held: Vec<(GeodeType, u16)>,
+        /// Item to look for.
+        #[arg(long = "want", required = true)]
+        wanted: Vec<String>,
+    },
+}
+
+fn parse_held(s: &str) -> Result<(GeodeType, u16)> {
+    let (geode_type, count): (&str, &str) = s
+        .split_once(':')
+        .context("Geodes held must be <geode type>:<count>.")?;
+
+    Ok((
+        geode_type.parse::<GeodeType>()?,
+        count.parse::<u16>().context("Error parsing count.")?,
+    ))
+}
+
+/// Without a subcommand, the arguments are those of `predict`.
+#[derive(Parser)]
+struct Arguments {
+    #[command(subcommand)]
+    command: Command,
+}
+
+impl Arguments {
+    /// Parses the arguments, predicting if the first one is an option rather than a subcommand, as
+    /// in `cli --platform pc --seed 123456789`.
+    fn parse_with_default_command() -> Self {
+        let mut arguments: Vec<OsString> = args_os().collect();
+        if arguments.get(1usize).is_some_and(|argument: &OsString| {
+            argument.to_str().is_some_and(|argument: &str| {
+                argument.starts_with('-')
+                    && !["-h", "--help", "-V", "--version"].contains(&argument)
+            })
+        }) {
+            arguments.insert(1usize, OsString::from("predict"));
+        }
+
+        Self::parse_from(arguments)
+    }
+}
+
+/// The `days` dates from `date`, or an error if the last one is past `i32::MAX`.
+fn dates(date: i32, days: u16) -> Result<Range<i32>> {
+    Ok(date..date
+        .checked_add(days as i32)
+        .context("Dates are out of range.")?)
+}
+
+/// The day after the configured date, which luck is predicted for.
+fn next_date(configuration: &Configuration) -> Result<i32> {
+    configuration
+        .date
+        .unwrap_or(1i32)
+        .checked_add(1i32)
+        .context("Date is out of range.")
+}
+
+/// The `steps` step totals from the configured steps taken.
+fn steps_taken_range(configuration: &Configuration, steps: u16) -> Result<Range<u32>> {
+    let steps_taken: u32 = configuration.steps_taken.unwrap_or(0u32);
+
+    Ok(steps_taken
+        ..steps_taken
+            .checked_add(steps as u32)
+            .context("Steps taken are out of range.")?)
+}
+
+fn print_stock<T: StockPredictor>(
+    out: &mut dyn Write,
+    name: &str,
+    configuration: &Configuration,
+    date: i32,
+    days: u16,
+) -> Result<()> {
+    for stock_day in get_stock_days::<T>(configuration, dates(date, days)?)? {
+        let StockDay { date, stock_items } = stock_day;
+        for stock_item in stock_items {
+            writeln!(
+                out,
+                "{}\t{}\t{}\t{}g\tx{}",
+                name,
+                format_date(date),
+                stock_item.item.name(stock_item.id),
+                stock_item.price,
+                stock_item.quantity,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+fn print_geodes(out: &mut dyn Write, configuration: &Configuration, geodes: u16) -> Result<()> {
+    let geodes_cracked: i32 = configuration.geodes_cracked.unwrap_or(0u16) as i32;
+    for geodes_cracked in geodes_cracked..geodes_cracked + geodes as i32 {
+        for (geode_type, geode) in GEODE_TYPES
+            .iter()
+            .zip(get_geodes(configuration, geodes_cracked)?)
+        {
+            writeln!(
+                out,
+                "Geodes\t{}\t{}\t{}\tx{}",
+                geodes_cracked,
+                geode_type,
+                geode.item.name(geode.id),
+                geode.quantity,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+fn print_luck(out: &mut dyn Write, configuration: &Configuration, steps: u16) -> Result<()> {
+    let date: i32 = next_date(configuration)?;
+    for steps_taken in steps_taken_range(configuration, steps)? {
+        let luck: f64 = get_daily_luck(configuration, date, steps_taken)?;
+        writeln!(
+            out,
+            "Luck\t{}\t{}\t{:.3}\t{}",
+            format_date(date),
+            steps_taken,
+            luck,
+            SpiritsMood::from_luck(luck),
+        )?;
+    }
+
+    Ok(())
+}
+
+fn print_weather(out: &mut dyn Write, date: i32, days: u16) -> Result<()> {
+    for date in dates(date, days)? {
+        let weather_chances: WeatherChances = get_weather_chances(date);
+        writeln!(
+            out,
+            "Weather\t{}\tsunny {:.3}\train {:.3}\tstorm {:.3}\tsnow {:.3}\twind {:.3}\t{}",
+            format_date(date),
+            weather_chances.sunny,
+            weather_chances.rain,
+            weather_chances.storm,
+            weather_chances.snow,
+            weather_chances.wind,
+            weather_chances.reason.unwrap_or_default(),
+        )?;
+    }
+
+    Ok(())
+}
+
+fn print_farm_events(
+    out: &mut dyn Write,
+    configuration: &Configuration,
+    date: i32,
+    days: u16,
+) -> Result<()> {
+    for date in dates(date, days)? {
+        if let Some(farm_event) = get_farm_event(configuration, date)? {
+            writeln!(
+                out,
+                "Farm events\t{}\t{}\t{}",
+                format_date(date),
+                farm_event,
+                farm_event.requirement(),
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+fn print_mines(
+    out: &mut dyn Write,
+    configuration: &Configuration,
+    date: i32,
+    days: u16,
+) -> Result<()> {
+    for date in dates(date, days)? {
+        for floor in get_mine_floors(configuration, date, 1u8..=MINES_BOTTOM)? {
+            if floor.is_normal() {
+                continue;
+            }
+
+            let mut modifiers: Vec<String> = Vec::new();
+            if let Some(infestation) = floor.infestation {
+                modifiers.push(infestation.to_string());
+            }
+            if floor.dark {
+                modifiers.push("Dark".to_string());
+            }
+            if floor.mushroom {
+                modifiers.push("Mushroom".to_string());
+            }
+            writeln!(
+                out,
+                "Mines\t{}\t{}\t{}",
+                format_date(date),
+                floor.level,
+                modifiers.join(", "),
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+fn print_trash_cans(
+    out: &mut dyn Write,
+    configuration: &Configuration,
+    date: i32,
+    days: u16,
+) -> Result<()> {
+    let format_find = |find: Option<TrashFind>| match find {
+        Some(find) => format!(
+            "{}\t{}",
+            find.trash_item.name(),
+            find.luck
+                .map_or("any".to_string(), |luck: f64| format!("{:.3}", luck)),
+        ),
+        None => "\t".to_string(),
+    };
+
+    for date in dates(date, days)? {
+        for trash_can_loot in get_trash_cans_loot(configuration, date)? {
+            if trash_can_loot.find.is_none() {
+                continue;
+            }
+
+            writeln!(
+                out,
+                "Trash cans\t{}\t{}\t{}\t{}",
+                format_date(date),
+                trash_can_loot.trash_can,
+                format_find(trash_can_loot.find),
+                format_find(trash_can_loot.special),
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+fn predict(
+    out: &mut dyn Write,
+    configuration: &Configuration,
+    days: u16,
+    geodes: u16,
+    steps: u16,
+    predictors: Vec<Predictor>,
+) -> Result<()> {
+    let date: i32 = configuration.date.unwrap_or(1i32);
+
+    for predictor in predictors {
+        match predictor {
+            Predictor::TravelingCart => print_stock::<TravelingCartPredictor>(
+                out,
+                "Traveling Cart",
+                configuration,
+                date,
+                days,
+            )?,
+            Predictor::Krobus => {
+                print_stock::<KrobusPredictor>(out, "Krobus", configuration, date, days)?
+            }
+            Predictor::Sandy => {
+                print_stock::<SandyPredictor>(out, "Sandy", configuration, date, days)?
+            }
+            Predictor::Pierre => {
+                print_stock::<PierrePredictor>(out, "Pierre", configuration, date, days)?
+            }
+            Predictor::Joja => {
+                print_stock::<JojaPredictor>(out, "Joja", configuration, date, days)?
+            }
+            Predictor::Saloon => {
+                print_stock::<SaloonPredictor>(out, "Saloon", configuration, date, days)?
+            }
+            Predictor::Geodes => print_geodes(out, configuration, geodes)?,
+            Predictor::Luck => print_luck(out, configuration, steps)?,
+            Predictor::Weather => print_weather(out, date, days)?,
+            Predictor::FarmEvents => print_farm_events(out, configuration, date, days)?,
+            Predictor::Mines => print_mines(out, configuration, date, days)?,
+            Predictor::TrashCans => print_trash_cans(out, configuration, date, days)?,
+        }
+    }
+
+    Ok(())
+}
+
+fn export(
+    out: &mut dyn Write,
+    configuration: &Configuration,
+    days: u16,
+    geodes: u16,
+    steps: u16,
+    predictors: Vec<Predictor>,
+    format: ExportFormat,
+) -> Result<()> {
+    let date: i32 = configuration.date.unwrap_or(1i32);
+    let dates: Range<i32> = dates(date, days)?;
+    let geodes_cracked: i32 = configuration.geodes_cracked.unwrap_or(0u16) as i32;
+
+    let mut tables: Vec<ExportTable> = Vec::new();
+    for predictor in predictors {
+        tables.push(match predictor {
+            Predictor::TravelingCart => export_stock::<TravelingCartPredictor>(
+                "Traveling Cart",
+                configuration,
+                dates.clone(),
+            )?,
+            Predictor::Krobus => {
+                export_stock::<KrobusPredictor>("Krobus", configuration, dates.clone())?
+            }
+            Predictor::Sandy => {
+                export_stock::<SandyPredictor>("Sandy", configuration, dates.clone())?
+            }
+            Predictor::Pierre => {
+                export_stock::<PierrePredictor>("Pierre", configuration, dates.clone())?
+            }
+            Predictor::Joja => export_stock::<JojaPredictor>("Joja", configuration, dates.clone())?,
+            Predictor::Saloon => {
+                export_stock::<SaloonPredictor>("Saloon", configuration, dates.clone())?
+            }
+            Predictor::Geodes => export_geodes(
+                configuration,
+                geodes_cracked..geodes_cracked + geodes as i32,
+            )?,
+            Predictor::Luck => {
+                export_luck(configuration, steps_taken_range(configuration, steps)?)?
+            }
+            Predictor::Weather => export_weather(dates.clone())?,
+            Predictor::FarmEvents => export_farm_events(configuration, dates.clone())?,
+            Predictor::Mines => export_mines(configuration, dates.clone())?,
+            Predictor::TrashCans => export_trash_cans(configuration, dates.clone())?,
+        });
+    }
+
+    writeln!(out, "{}", export_tables(&tables, format).trim_end())?;
+
+    Ok(())
+}
+
+fn plan(
+    out: &mut dyn Write,
+    configuration: &Configuration,
+    held: &[(GeodeType, u16)],
+    wanted: &[String],
+) -> Result<()> {
+    let mut held_counts: [u16; 6usize] = [0u16; 6usize];
+    for (geode_type, count) in held {
+        let index: usize = GEODE_TYPES
+            .iter()
+            .position(|other: &GeodeType| other == geode_type)
+            .context("Error getting geode type.")?;
+        held_counts[index] = held_counts[index].saturating_add(*count);
+    }
+
+    let plan: GeodePlan = plan_geodes(configuration, held_counts, wanted)?;
+    if !plan.optimal {
+        eprintln!("Too many ways to find the wanted items, so this plan may not be optimal.");
+    }
+    for planned_crack in plan.cracks {
+        writeln!(
+            out,
+            "Plan\t{}\t{}\t{}\tx{}{}",
+            planned_crack.geodes_cracked,
+            planned_crack.geode_type,
+            planned_crack.geode.item.name(planned_crack.geode.id),
+            planned_crack.geode.quantity,
+            if planned_crack.wanted { "\twanted" } else { "" },
+        )?;
+    }
+
+    Ok(())
+}
+
+fn search(
+    out: &mut dyn Write,
+    configuration: &Configuration,
+    predicates: &[SeedPredicate],
+    seeds: RangeInclusive<i32>,
+    limit: usize,
+    threads: usize,
+) -> Result<()> {
+    for seed in scan_seeds(configuration, seeds, predicates, threads, limit)? {
+        writeln!(out, "{}", seed)?;
+    }
+
+    Ok(())
+}
+
+fn run(out: &mut dyn Write, arguments: Arguments) -> Result<()> {
+    match arguments.command {
+        Command::Predict {
+            configuration,
+            seed,
+            date,
+            days,
+            geodes,
+            steps,
+            predictors,
+            format,
+        } => {
+            let configuration: Configuration = configuration.configuration(seed, date)?;
+            let predictors: Vec<Predictor> = if predictors.is_empty() {
+                Predictor::value_variants().to_vec()
+            } else {
+                predictors
+            };
+
+            match format {
+                Some(format) => {
+                    export(out, &configuration, days, geodes, steps, predictors, format)
+                }
+                None => predict(out, &configuration, days, geodes, steps, predictors),
+            }
+        }
+        Command::Search {
+            configuration,
+            predicates,
+            date,
+            from,
+            to,
+            limit,
+            threads,
+        } => search(
+            out,
+            &configuration.configuration(Some(from), date)?,
+            &predicates,
+            from..=to,
+            limit,
+            match threads {
+                Some(threads) => threads,
+                None => available_parallelism()?.get(),
+            },
+        ),
+        Command::Plan {
+            configuration,
+            seed,
+            held,
+            wanted,
+        } => plan(
+            out,
+            &configuration.configuration(seed, None)?,
+            &held,
+            &wanted,
+        ),
+    }
+}
+
+fn main() -> Result<()> {
+    let mut out: BufWriter<_> = BufWriter::new(stdout().lock());
+    match run(&mut out, Arguments::parse_with_default_command()).and_then(|()| Ok(out.flush()?)) {
+        // The reader went away, e.g. when piped into head.
+        Err(error)
+            if error
+                .downcast_ref::<std::io::Error>()
+                .is_some_and(|error| error.kind() == ErrorKind::BrokenPipe) =>
+        {
+            Ok(())
+        }
+        result => result,
+    }
+}
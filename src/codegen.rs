@@ -56,6 +56,21 @@ pub struct Hats {
     pub is_prismatic: bool,
 }
 
+pub struct BundleItem {
+    /// -1 for gold in the vault.
+    pub id: i32,
+    pub quantity: u32,
+    pub quality: u8,
+}
+
+pub struct Bundle {
+    pub room: &'static str,
+    pub name: &'static str,
+    pub items: &'static [BundleItem],
+    /// How many of the items complete the bundle.
+    pub count: u8,
+}
+
 include!(concat!(env!("OUT_DIR"), "/codegen.rs"));
 
 pub static OBJECT_INFORMATION_OFF_LIMIT: phf::Set<u16> = phf::phf_set!(
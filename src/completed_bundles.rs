@@ -0,0 +1,43 @@
+use anyhow::Result;
+
+use crate::predictors::bundles::BundleSlot;
+use crate::storage::LocalStorage;
+
+// Completed slots are stored per seed, as comma separated bundle index and slot pairs.
+const COMPLETED_BUNDLES: LocalStorage<Vec<BundleSlot>> = LocalStorage {
+    prefix: "bundles:",
+    description: "completed bundles",
+    serialise: |completed: &Vec<BundleSlot>| {
+        Some(
+            completed
+                .iter()
+                .map(|(index, slot): &BundleSlot| format!("{}:{}", index, slot))
+                .collect::<Vec<String>>()
+                .join(","),
+        )
+    },
+    deserialise: |completed: &str| {
+        Some(
+            completed
+                .split(',')
+                .filter_map(|slot: &str| {
+                    let (index, slot): (&str, &str) = slot.split_once(':')?;
+                    Some((index.parse::<u16>().ok()?, slot.parse::<u8>().ok()?))
+                })
+                .collect(),
+        )
+    },
+};
+
+/// Returns no completed slots if none are stored for the seed.
+pub fn load_completed_bundles(seed: i32) -> Vec<BundleSlot> {
+    COMPLETED_BUNDLES
+        .load(&seed.to_string())
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+}
+
+pub fn save_completed_bundles(seed: i32, completed: &[BundleSlot]) -> Result<()> {
+    COMPLETED_BUNDLES.save(&seed.to_string(), &completed.to_vec())
+}
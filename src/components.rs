@@ -2,11 +2,14 @@ pub mod button;
 pub mod configuration_form;
 pub mod date;
 pub mod dropdown;
+pub mod export;
+pub mod file_input;
 pub mod filter;
 pub mod input;
 pub mod jump;
 pub mod message;
 pub mod navigation;
+pub mod profiles;
 pub mod stock_table;
 pub mod table;
 pub mod tabs;
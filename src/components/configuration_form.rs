@@ -4,9 +4,12 @@ use yew::prelude::*;
 use crate::components::button::{Button, ButtonColour};
 use crate::components::date::DatePicker;
 use crate::components::dropdown::Dropdown;
+use crate::components::file_input::FileInput;
 use crate::components::input::Input;
 use crate::components::message::{Message, MessageColour};
+use crate::components::profiles::Profiles;
 use crate::configuration::{Configuration, Platform};
+use crate::save::configuration_from_save;
 
 #[derive(Clone, Copy, PartialEq)]
 pub enum YesNo {
@@ -14,6 +17,16 @@ pub enum YesNo {
     No,
 }
 
+impl From<bool> for YesNo {
+    fn from(value: bool) -> Self {
+        if value {
+            YesNo::Yes
+        } else {
+            YesNo::No
+        }
+    }
+}
+
 impl Display for YesNo {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -30,18 +43,52 @@ impl Display for YesNo {
 #[derive(Properties, PartialEq)]
 pub struct ConfigurationFormProperties {
     pub updated: Callback<Configuration>,
+    /// Fills the form, e.g. from a shared URL.
+    #[prop_or(None)]
+    pub initial: Option<Configuration>,
 }
 
 #[component]
 pub fn ConfigurationForm(properties: &ConfigurationFormProperties) -> Html {
-    let platform = use_state(|| None::<Platform>);
-    let seed = use_state(|| None::<i32>);
-    let show_optional = use_state(|| false);
-    let date = use_state(|| None::<i32>);
-    let geodes_cracked = use_state(|| None::<u16>);
-    let mine_level = use_state(|| None::<u8>);
-    let qis_crop = use_state(|| None::<bool>);
-    let golden_helmet = use_state(|| None::<bool>);
+    let initial: Option<Configuration> = properties.initial.clone();
+    let platform = use_state(|| initial.as_ref().map(|initial| initial.platform));
+    let seed = use_state(|| initial.as_ref().map(|initial| initial.seed));
+    let show_optional = use_state(|| {
+        initial.as_ref().is_some_and(|initial| {
+            initial.date.is_some()
+                || initial.geodes_cracked.is_some()
+                || initial.mine_level.is_some()
+                || initial.qis_crop.is_some()
+                || initial.golden_helmet.is_some()
+                || initial.year_one_completable.is_some()
+                || initial.visits_until_y1_guarantee.is_some()
+                || initial.player_id.is_some()
+                || initial.steps_taken.is_some()
+                || initial.trash_cans_checked.is_some()
+        })
+    });
+    let date = use_state(|| initial.as_ref().and_then(|initial| initial.date));
+    let geodes_cracked = use_state(|| initial.as_ref().and_then(|initial| initial.geodes_cracked));
+    let mine_level = use_state(|| initial.as_ref().and_then(|initial| initial.mine_level));
+    let qis_crop = use_state(|| initial.as_ref().and_then(|initial| initial.qis_crop));
+    let golden_helmet = use_state(|| initial.as_ref().and_then(|initial| initial.golden_helmet));
+    let year_one_completable = use_state(|| {
+        initial
+            .as_ref()
+            .and_then(|initial| initial.year_one_completable)
+    });
+    let visits_until_y1_guarantee = use_state(|| {
+        initial
+            .as_ref()
+            .and_then(|initial| initial.visits_until_y1_guarantee)
+    });
+    let player_id = use_state(|| initial.as_ref().and_then(|initial| initial.player_id));
+    let steps_taken = use_state(|| initial.as_ref().and_then(|initial| initial.steps_taken));
+    let trash_cans_checked = use_state(|| {
+        initial
+            .as_ref()
+            .and_then(|initial| initial.trash_cans_checked)
+    });
     let message = use_state(|| None::<String>);
 
     let platform_updated = {
@@ -96,51 +143,166 @@ pub fn ConfigurationForm(properties: &ConfigurationFormProperties) -> Html {
         })
     };
 
-    let go_updated = {
-        let platform = platform.clone();
+    let year_one_completable_updated = {
+        let year_one_completable = year_one_completable.clone();
+        Callback::from(move |value: Option<YesNo>| {
+            year_one_completable.set(match value {
+                Some(YesNo::Yes) => Some(true),
+                Some(YesNo::No) => Some(false),
+                None => None,
+            });
+        })
+    };
+
+    let visits_until_y1_guarantee_updated = {
+        let visits_until_y1_guarantee = visits_until_y1_guarantee.clone();
+        Callback::from(move |value: Option<i32>| visits_until_y1_guarantee.set(value))
+    };
+
+    let player_id_updated = {
+        let player_id = player_id.clone();
+        Callback::from(move |value: Option<i64>| player_id.set(value))
+    };
+
+    let steps_taken_updated = {
+        let steps_taken = steps_taken.clone();
+        Callback::from(move |value: Option<u32>| steps_taken.set(value))
+    };
+
+    let trash_cans_checked_updated = {
+        let trash_cans_checked = trash_cans_checked.clone();
+        Callback::from(move |value: Option<u32>| trash_cans_checked.set(value))
+    };
+
+    let save_updated = {
         let seed = seed.clone();
+        let show_optional = show_optional.clone();
         let date = date.clone();
         let geodes_cracked = geodes_cracked.clone();
         let mine_level = mine_level.clone();
         let qis_crop = qis_crop.clone();
         let golden_helmet = golden_helmet.clone();
+        let year_one_completable = year_one_completable.clone();
+        let visits_until_y1_guarantee = visits_until_y1_guarantee.clone();
+        let player_id = player_id.clone();
+        let steps_taken = steps_taken.clone();
+        let trash_cans_checked = trash_cans_checked.clone();
         let message = message.clone();
-        let updated = properties.updated.clone();
-        Callback::from(move |_: ()| {
-            match (*platform, *seed) {
-                (Some(platform), Some(seed)) => {
-                    updated.emit(Configuration {
-                        platform,
-                        seed,
-                        date: *date,
-                        geodes_cracked: *geodes_cracked,
-                        mine_level: *mine_level,
-                        qis_crop: *qis_crop,
-                        golden_helmet: *golden_helmet,
-                    });
+        Callback::from(move |save: anyhow::Result<String>| {
+            // Saves don't record the platform, so the one passed is ignored.
+            match save.and_then(|save: String| configuration_from_save(&save, Platform::PC)) {
+                Ok(configuration) => {
+                    seed.set(Some(configuration.seed));
+                    show_optional.set(true);
+                    date.set(configuration.date);
+                    geodes_cracked.set(configuration.geodes_cracked);
+                    mine_level.set(configuration.mine_level);
+                    qis_crop.set(configuration.qis_crop);
+                    golden_helmet.set(configuration.golden_helmet);
+                    year_one_completable.set(configuration.year_one_completable);
+                    visits_until_y1_guarantee.set(configuration.visits_until_y1_guarantee);
+                    player_id.set(configuration.player_id);
+                    steps_taken.set(configuration.steps_taken);
+                    trash_cans_checked.set(configuration.trash_cans_checked);
                     message.set(None);
                 }
-                _ => {
-                    message.set(Some("Platform and seed must be set.".to_string()));
+                Err(error) => {
+                    message.set(Some(error.to_string()));
                 }
             }
         })
     };
 
+    let profile_loaded = {
+        let platform = platform.clone();
+        let seed = seed.clone();
+        let show_optional = show_optional.clone();
+        let date = date.clone();
+        let geodes_cracked = geodes_cracked.clone();
+        let mine_level = mine_level.clone();
+        let qis_crop = qis_crop.clone();
+        let golden_helmet = golden_helmet.clone();
+        let year_one_completable = year_one_completable.clone();
+        let visits_until_y1_guarantee = visits_until_y1_guarantee.clone();
+        let player_id = player_id.clone();
+        let steps_taken = steps_taken.clone();
+        let trash_cans_checked = trash_cans_checked.clone();
+        let message = message.clone();
+        let updated = properties.updated.clone();
+        Callback::from(move |configuration: Configuration| {
+            platform.set(Some(configuration.platform));
+            seed.set(Some(configuration.seed));
+            show_optional.set(true);
+            date.set(configuration.date);
+            geodes_cracked.set(configuration.geodes_cracked);
+            mine_level.set(configuration.mine_level);
+            qis_crop.set(configuration.qis_crop);
+            golden_helmet.set(configuration.golden_helmet);
+            year_one_completable.set(configuration.year_one_completable);
+            visits_until_y1_guarantee.set(configuration.visits_until_y1_guarantee);
+            player_id.set(configuration.player_id);
+            steps_taken.set(configuration.steps_taken);
+            trash_cans_checked.set(configuration.trash_cans_checked);
+            message.set(None);
+            // Switching farms shows its results straight away.
+            updated.emit(configuration);
+        })
+    };
+
+    let current: Option<Configuration> = match (*platform, *seed) {
+        (Some(platform), Some(seed)) => Some(Configuration {
+            platform,
+            seed,
+            date: *date,
+            geodes_cracked: *geodes_cracked,
+            mine_level: *mine_level,
+            qis_crop: *qis_crop,
+            golden_helmet: *golden_helmet,
+            year_one_completable: *year_one_completable,
+            visits_until_y1_guarantee: *visits_until_y1_guarantee,
+            player_id: *player_id,
+            steps_taken: *steps_taken,
+            trash_cans_checked: *trash_cans_checked,
+        }),
+        _ => None,
+    };
+
+    let go_updated = {
+        let current = current.clone();
+        let message = message.clone();
+        let updated = properties.updated.clone();
+        Callback::from(move |_: ()| match current.clone() {
+            Some(configuration) => {
+                updated.emit(configuration);
+                message.set(None);
+            }
+            None => {
+                message.set(Some("Platform and seed must be set.".to_string()));
+            }
+        })
+    };
+
     html!(
         <section class="section">
             <h1 class="title">{ "Configuration" }</h1>
             <div class="container">
                 <div class="columns">
                     <div class="column">
-                        <Dropdown<Platform> updated={ platform_updated } items={ vec![Platform::PC, Platform::Switch] } label="Platform" />
-                        <Input<i32> updated={ seed_updated } label="Seed" />
+                        <Profiles configuration={ current } loaded={ profile_loaded } />
+                        <Dropdown<Platform> updated={ platform_updated } items={ vec![Platform::PC, Platform::Switch] } label="Platform" selected={ *platform } />
+                        <Input<i32> updated={ seed_updated } label="Seed" value={ *seed } />
                         <div class={ if *show_optional { "mb-3" } else { "is-hidden" } }>
-                            <DatePicker updated={ date_updated } label="Date (optional)" />
-                            <Input<u16> updated={ geodes_cracked_updated } label="Geodes cracked (optional)" />
-                            <Input<u8> updated={ mine_level_updated } label="Deepest mine level (optional)" />
-                            <Dropdown<YesNo> updated={ qis_crop_updated } items={ vec![YesNo::Yes, YesNo::No] } label="Qi's crop quest (optional)" />
-                            <Dropdown<YesNo> updated={ golden_helmet_updated } items={ vec![YesNo::Yes, YesNo::No] } label="Golden helmet received (optional)" />
+                            <FileInput updated={ save_updated } label="Fill from farm save file (optional)" />
+                            <DatePicker updated={ date_updated } label="Date (optional)" value={ *date } />
+                            <Input<u16> updated={ geodes_cracked_updated } label="Geodes cracked (optional)" value={ *geodes_cracked } />
+                            <Input<u8> updated={ mine_level_updated } label="Deepest mine level (optional)" value={ *mine_level } />
+                            <Dropdown<YesNo> updated={ qis_crop_updated } items={ vec![YesNo::Yes, YesNo::No] } label="Qi's crop quest (optional)" selected={ qis_crop.map(YesNo::from) } />
+                            <Dropdown<YesNo> updated={ golden_helmet_updated } items={ vec![YesNo::Yes, YesNo::No] } label="Golden helmet received (optional)" selected={ golden_helmet.map(YesNo::from) } />
+                            <Dropdown<YesNo> updated={ year_one_completable_updated } items={ vec![YesNo::Yes, YesNo::No] } label="Guarantee year 1 completable (optional)" selected={ year_one_completable.map(YesNo::from) } />
+                            <Input<i32> updated={ visits_until_y1_guarantee_updated } label="Traveling cart visits until year 1 guarantee (optional)" value={ *visits_until_y1_guarantee } />
+                            <Input<i64> updated={ player_id_updated } label="Player ID (optional)" value={ *player_id } />
+                            <Input<u32> updated={ steps_taken_updated } label="Steps taken so far today (optional)" value={ *steps_taken } />
+                            <Input<u32> updated={ trash_cans_checked_updated } label="Trash cans checked (optional)" value={ *trash_cans_checked } />
                         </div>
                         <Button updated={ show_optional_updated } colour={ ButtonColour::Default } label={ format!("{} optional parameters", if *show_optional { "Hide" } else { "Show" }) } />
                         <Button updated={ go_updated } colour={ ButtonColour::Primary } label="Go" />
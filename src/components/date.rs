@@ -1,6 +1,8 @@
 use web_sys::{HtmlInputElement, HtmlSelectElement};
 use yew::prelude::*;
 
+use crate::predictors::util::{day_number, season_number, year_number};
+
 fn compute_date(year: Option<u16>, season: Option<u8>, day: Option<u8>) -> Option<i32> {
     match (year, season, day) {
         (Some(year), Some(season), Some(day)) => {
@@ -16,6 +18,9 @@ pub struct DatePickerProperties {
     pub updated: Callback<Option<i32>>,
     #[prop_or(AttrValue::from("Date"))]
     pub label: AttrValue,
+    /// Replaces the fields when it changes to a date they don't already make up.
+    #[prop_or(None)]
+    pub value: Option<i32>,
 }
 
 #[component]
@@ -24,6 +29,28 @@ pub fn DatePicker(properties: &DatePickerProperties) -> Html {
     let season = use_state(|| None::<u8>);
     let day = use_state(|| None::<u8>);
 
+    {
+        let year = year.clone();
+        let season = season.clone();
+        let day = day.clone();
+        use_effect_with(properties.value, move |value: &Option<i32>| {
+            if compute_date(*year, *season, *day) != *value {
+                match *value {
+                    Some(date) => {
+                        year.set(Some(year_number(date) as u16 + 1u16));
+                        season.set(Some(season_number(date) + 1u8));
+                        day.set(Some(day_number(date) + 1u8));
+                    }
+                    None => {
+                        year.set(None);
+                        season.set(None);
+                        day.set(None);
+                    }
+                }
+            }
+        });
+    }
+
     let year_updated = {
         let year = year.clone();
         let season = season.clone();
@@ -7,6 +7,8 @@ pub struct DropdownProperties<T: Copy + PartialEq + ToString + 'static> {
     pub items: Vec<T>,
     #[prop_or(AttrValue::from("Dropdown"))]
     pub label: AttrValue,
+    #[prop_or(None)]
+    pub selected: Option<T>,
 }
 
 #[component]
@@ -34,8 +36,8 @@ pub fn Dropdown<T: Copy + PartialEq + ToString + 'static>(
             <div class="control">
                 <div class="select">
                     <select onchange={ selected_updated }>
-                        <option disabled=true hidden=true selected=true>{ properties.label.clone() }</option>
-                        { for properties.items.iter().map(|item| html!(<option>{ item.to_string() }</option>)) }
+                        <option disabled=true hidden=true selected={ properties.selected.is_none() }>{ properties.label.clone() }</option>
+                        { for properties.items.iter().map(|item| html!(<option selected={ properties.selected == Some(*item) }>{ item.to_string() }</option>)) }
                     </select>
                 </div>
             </div>
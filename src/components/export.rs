@@ -0,0 +1,92 @@
+use anyhow::{anyhow, Result};
+use gloo::file::{Blob, ObjectUrl};
+use gloo::timers::callback::Timeout;
+use gloo::utils::document;
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::HtmlAnchorElement;
+use yew::prelude::*;
+
+use crate::components::button::{Button, ButtonColour};
+use crate::components::input::Input;
+use crate::components::message::{Message, MessageColour};
+use crate::export::{ExportFormat, ExportTable};
+
+/// How long the download link stays valid, as some browsers start the download asynchronously.
+const REVOKE_DELAY: u32 = 60000u32;
+
+fn download(file_name: &str, format: ExportFormat, contents: &str) -> Result<()> {
+    let url: ObjectUrl =
+        ObjectUrl::from(Blob::new_with_options(contents, Some(format.mime_type())));
+    let anchor: HtmlAnchorElement = document()
+        .create_element("a")
+        .ok()
+        .and_then(|element| element.dyn_into::<HtmlAnchorElement>().ok())
+        .ok_or_else(|| anyhow!("Error creating download link."))?;
+    anchor.set_href(&url);
+    anchor.set_download(file_name);
+    anchor.click();
+    Timeout::new(REVOKE_DELAY, move || drop(url)).forget();
+
+    Ok(())
+}
+
+/// Downloads the results shown, or a given number of them from the same start, as CSV or JSON.
+#[derive(Properties, PartialEq)]
+pub struct ExportProperties {
+    /// How many dates, geodes cracked or step totals are shown.
+    pub visible: u16,
+    /// Builds the table for that many from the one shown first.
+    pub table: Callback<u16, Result<ExportTable>>,
+    #[prop_or(AttrValue::from("Number to export (optional)"))]
+    pub label: AttrValue,
+}
+
+#[component]
+pub fn Export(properties: &ExportProperties) -> Html {
+    let count = use_state_eq(|| None::<u16>);
+    let message = use_state_eq(|| None::<String>);
+
+    let count_updated = {
+        let count = count.clone();
+        Callback::from(move |value: Option<u16>| count.set(value))
+    };
+
+    let exported = |format: ExportFormat| {
+        let count = count.clone();
+        let message = message.clone();
+        let table = properties.table.clone();
+        let visible: u16 = properties.visible;
+        Callback::from(move |_: ()| {
+            match table
+                .emit(count.unwrap_or(visible))
+                .and_then(|table: ExportTable| {
+                    download(&table.file_name(format), format, &table.export(format))
+                }) {
+                Ok(()) => message.set(None),
+                Err(error) => message.set(Some(error.to_string())),
+            }
+        })
+    };
+
+    html!(
+        <>
+            {
+                match (*message).clone() {
+                    Some(message) => html!(<Message colour={ MessageColour::Danger } body={ message } />),
+                    None => html!(),
+                }
+            }
+            <div class="columns is-vcentered">
+                <div class="column">
+                    <Input<u16> updated={ count_updated } label={ properties.label.clone() } value={ *count } />
+                </div>
+                <div class="column is-narrow">
+                    <Button updated={ exported(ExportFormat::Csv) } colour={ ButtonColour::Default } label="Export CSV" />
+                </div>
+                <div class="column is-narrow">
+                    <Button updated={ exported(ExportFormat::Json) } colour={ ButtonColour::Default } label="Export JSON" />
+                </div>
+            </div>
+        </>
+    )
+}
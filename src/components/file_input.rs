@@ -0,0 +1,58 @@
+use anyhow::{anyhow, Result};
+use gloo::file::callbacks::{read_as_text, FileReader};
+use gloo::file::File;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+/// Reads the chosen file as text and emits its contents.
+#[derive(Properties, PartialEq)]
+pub struct FileInputProperties {
+    pub updated: Callback<Result<String>>,
+    #[prop_or(AttrValue::from("File"))]
+    pub label: AttrValue,
+}
+
+#[component]
+pub fn FileInput(properties: &FileInputProperties) -> Html {
+    let name = use_state(|| None::<String>);
+    // The read is cancelled if the reader is dropped, so keep it until the next file is chosen.
+    let reader = use_mut_ref(|| None::<FileReader>);
+
+    let file_updated = {
+        let name = name.clone();
+        let reader = reader.clone();
+        let updated = properties.updated.clone();
+        Callback::from(move |event: Event| {
+            let file: File = match event
+                .target_unchecked_into::<HtmlInputElement>()
+                .files()
+                .and_then(|files| files.get(0))
+            {
+                Some(file) => File::from(file),
+                None => return,
+            };
+
+            name.set(Some(file.name()));
+
+            let updated = updated.clone();
+            *reader.borrow_mut() = Some(read_as_text(&file, move |result| {
+                updated.emit(result.map_err(|error| anyhow!("Error reading file: {}", error)));
+            }));
+        })
+    };
+
+    html!(
+        <div class="field">
+            <label class="label">{ properties.label.clone() }</label>
+            <div class="file has-name">
+                <label class="file-label">
+                    <input class="file-input" type="file" onchange={ file_updated } />
+                    <span class="file-cta">
+                        <span class="file-label">{ "Choose a file" }</span>
+                    </span>
+                    <span class="file-name">{ (*name).clone().unwrap_or_default() }</span>
+                </label>
+            </div>
+        </div>
+    )
+}
@@ -4,11 +4,14 @@ use yew::prelude::*;
 #[derive(Properties, PartialEq)]
 pub struct FilterProperties {
     pub updated: Callback<String>,
+    /// Initial text.
+    #[prop_or_default]
+    pub value: String,
 }
 
 #[component]
 pub fn Filter(properties: &FilterProperties) -> Html {
-    let value: UseStateHandle<String> = use_state_eq(|| "".to_string());
+    let value: UseStateHandle<String> = use_state_eq(|| properties.value.clone());
 
     let value_updated: Callback<InputEvent> = {
         let value: UseStateHandle<String> = value.clone();
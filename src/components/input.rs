@@ -8,13 +8,25 @@ pub struct InputProperties<T: Copy + FromStr + PartialEq + ToString + 'static> {
     pub updated: Callback<Option<T>>,
     #[prop_or(AttrValue::from("Input"))]
     pub label: AttrValue,
+    /// Replaces the text when it changes to something the text doesn't already parse to.
+    #[prop_or(None)]
+    pub value: Option<T>,
 }
 
 #[component]
 pub fn Input<T: Copy + FromStr + PartialEq + ToString + 'static>(
     properties: &InputProperties<T>,
 ) -> Html {
-    let text = use_state(|| String::new());
+    let text = use_state(String::new);
+
+    {
+        let text = text.clone();
+        use_effect_with(properties.value, move |value: &Option<T>| {
+            if text.parse::<T>().ok() != *value {
+                text.set(value.map(|value: T| value.to_string()).unwrap_or_default());
+            }
+        });
+    }
 
     let value_updated = {
         let text = text.clone();
@@ -14,7 +14,7 @@ pub struct JumpProperties<T: Copy + FromStr + PartialEq + ToString + 'static> {
 pub fn Jump<T: Copy + FromStr + PartialEq + ToString + 'static>(
     properties: &JumpProperties<T>,
 ) -> Html {
-    let text = use_state(|| String::new());
+    let text = use_state(String::new);
     let parsed = use_state(|| None::<T>);
 
     let value_updated = {
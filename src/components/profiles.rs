@@ -0,0 +1,112 @@
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+use crate::components::message::{Message, MessageColour};
+use crate::configuration::Configuration;
+use crate::profiles::{delete_profile, load_profile, profile_names, save_profile};
+
+/// Named configurations kept in local storage, one per farm.
+#[derive(Properties, PartialEq)]
+pub struct ProfilesProperties {
+    /// What gets saved, `None` if the form is incomplete.
+    pub configuration: Option<Configuration>,
+    pub loaded: Callback<Configuration>,
+}
+
+#[component]
+pub fn Profiles(properties: &ProfilesProperties) -> Html {
+    let names = use_state(profile_names);
+    let name = use_state(String::new);
+    let message = use_state(|| None::<String>);
+
+    let selected_updated = {
+        let names = names.clone();
+        let name = name.clone();
+        let message = message.clone();
+        let loaded = properties.loaded.clone();
+        Callback::from(move |event: Event| {
+            let index = event
+                .target_unchecked_into::<HtmlSelectElement>()
+                .selected_index();
+            let selected: String = match names.get((index - 1) as usize) {
+                Some(selected) if index > 0 => selected.clone(),
+                _ => return,
+            };
+
+            match load_profile(&selected) {
+                Ok(configuration) => {
+                    loaded.emit(configuration);
+                    message.set(None);
+                }
+                Err(error) => message.set(Some(error.to_string())),
+            }
+            name.set(selected);
+        })
+    };
+
+    let name_updated = {
+        let name = name.clone();
+        Callback::from(move |event: InputEvent| {
+            name.set(event.target_unchecked_into::<HtmlInputElement>().value());
+        })
+    };
+
+    let save_updated = {
+        let names = names.clone();
+        let name = name.clone();
+        let message = message.clone();
+        let configuration = properties.configuration.clone();
+        Callback::from(move |_: MouseEvent| {
+            let result = match (name.trim(), &configuration) {
+                ("", _) => Err("A profile name must be set.".to_string()),
+                (_, None) => Err("Platform and seed must be set.".to_string()),
+                (trimmed, Some(configuration)) => {
+                    save_profile(trimmed, configuration).map_err(|error| error.to_string())
+                }
+            };
+            message.set(result.err());
+            names.set(profile_names());
+        })
+    };
+
+    let delete_updated = {
+        let names = names.clone();
+        let name = name.clone();
+        let message = message.clone();
+        Callback::from(move |_: MouseEvent| {
+            message.set(
+                delete_profile(name.trim())
+                    .err()
+                    .map(|error| error.to_string()),
+            );
+            names.set(profile_names());
+            name.set(String::new());
+        })
+    };
+
+    html!(
+        <div class="field">
+            <label class="label">{ "Farm profile (optional)" }</label>
+            <div class="field has-addons">
+                <div class="control">
+                    <div class="select">
+                        <select onchange={ selected_updated }>
+                            <option disabled=true hidden=true selected={ !names.contains(&*name) }>{ "Profiles" }</option>
+                            { for names.iter().map(|item| html!(<option selected={ *item == *name }>{ item.clone() }</option>)) }
+                        </select>
+                    </div>
+                </div>
+                <div class="control is-expanded">
+                    <input class="input" oninput={ name_updated } placeholder="Profile name" type="text" value={ (*name).clone() } />
+                </div>
+                <div class="control">
+                    <button class="button is-primary" onclick={ save_updated }>{ "Save" }</button>
+                </div>
+                <div class="control">
+                    <button class="button is-danger" onclick={ delete_updated }>{ "Delete" }</button>
+                </div>
+            </div>
+            <Message colour={ MessageColour::Danger } body={ (*message).clone() } />
+        </div>
+    )
+}
@@ -3,15 +3,25 @@ use std::cmp::max;
 use anyhow::Result;
 use yew::prelude::*;
 
+use crate::components::button::{Button, ButtonColour};
 use crate::components::date::DateJump;
+use crate::components::export::Export;
 use crate::components::filter::Filter;
 use crate::components::message::{Message, MessageColour};
 use crate::components::navigation::{Navigation, NavigationDirection};
 use crate::components::table::{Table, TableAlign, TableCell, TableValue};
 use crate::configuration::Configuration;
+use crate::export::{export_stock, export_stock_matching, ExportTable};
+use crate::implementations::util::{stock_item_matches, stock_items_rows};
+use crate::location::View;
+use crate::predictors::bundles::BundleSlot;
+use crate::predictors::util::{StockItem, StockPredictor};
 
-pub fn stock_items_table_header() -> Vec<Vec<TableCell>> {
-    vec![vec![
+const FILTER_ITERATIONS: u16 = 1120u16;
+const FILTER_DAYS: u8 = 8u8;
+
+pub fn stock_items_table_header(bundles: bool) -> Vec<Vec<TableCell>> {
+    let mut header: Vec<TableCell> = vec![
         TableCell {
             value: TableValue::String(AttrValue::from("Date")),
             align: TableAlign::MiddleLeft,
@@ -36,15 +46,62 @@ pub fn stock_items_table_header() -> Vec<Vec<TableCell>> {
             rows: 1,
             columns: 1,
         },
-    ]]
+    ];
+    if bundles {
+        header.push(TableCell {
+            value: TableValue::String(AttrValue::from("Bundles")),
+            align: TableAlign::MiddleLeft,
+            rows: 1,
+            columns: 1,
+        });
+    }
+
+    vec![header]
+}
+
+pub fn stock_table_rows<T: StockPredictor>(
+    configuration: &Configuration,
+    date: i32,
+    filter: &str,
+    non_filter_iterations: u16,
+    completed_bundles: Option<&[BundleSlot]>,
+    bundles_only: bool,
+) -> Result<Vec<Vec<TableCell>>> {
+    let filtering: bool = !filter.is_empty() || bundles_only;
+    let iterations: u16 = if !filtering {
+        non_filter_iterations
+    } else {
+        FILTER_ITERATIONS
+    };
+    let mut days_generated: u8 = 0u8;
+    let mut table: Vec<Vec<TableCell>> = Vec::new();
+    for iteration in 0u16..iterations {
+        let date: i32 = date + iteration as i32;
+
+        let stock_items = match T::get_stock(configuration, date)? {
+            Some(stock_items) => stock_items,
+            None => continue,
+        };
+
+        if let Some(rows) =
+            stock_items_rows(&stock_items, date, filter, completed_bundles, bundles_only)
+        {
+            table.extend(rows);
+
+            days_generated += 1u8;
+            if filtering && days_generated >= FILTER_DAYS {
+                break;
+            }
+        }
+    }
+
+    Ok(table)
 }
 
 pub trait StockTableTrait {
-    fn get_stock(
-        configuration: &Configuration,
-        date: i32,
-        filter: &String,
-    ) -> Result<Vec<Vec<TableCell>>>;
+    type Predictor: StockPredictor;
+    /// Names exported files.
+    const NAME: &'static str;
 
     fn get_messages(configuration: &Configuration) -> Html;
 }
@@ -53,12 +110,33 @@ pub trait StockTableTrait {
 pub struct StockTableProperties {
     pub configuration: Configuration,
     pub navigation_step: i32,
+    pub view: View,
+    pub view_updated: Callback<View>,
+    /// Completed bundle slots, to flag the stock that open bundles need.
+    #[prop_or_default]
+    pub completed_bundles: Option<Vec<BundleSlot>>,
 }
 
 #[component]
 pub fn StockTable<T: StockTableTrait>(properties: &StockTableProperties) -> Html {
-    let date = use_state_eq(|| properties.configuration.date.unwrap_or(1));
-    let filter = use_state_eq(|| String::new());
+    let date = use_state_eq(|| {
+        properties
+            .view
+            .position
+            .unwrap_or(properties.configuration.date.unwrap_or(1))
+    });
+    let filter = use_state_eq(|| properties.view.filter.clone());
+    let bundles_only = use_state_eq(|| false);
+
+    {
+        let view_updated = properties.view_updated.clone();
+        use_effect_with((*date, (*filter).clone()), move |(date, filter)| {
+            view_updated.emit(View {
+                position: Some(*date),
+                filter: filter.clone(),
+            });
+        });
+    }
 
     let date_jump_updated = {
         let date = date.clone();
@@ -70,6 +148,11 @@ pub fn StockTable<T: StockTableTrait>(properties: &StockTableProperties) -> Html
         Callback::from(move |value: String| filter.set(value))
     };
 
+    let bundles_only_updated = {
+        let bundles_only = bundles_only.clone();
+        Callback::from(move |_: ()| bundles_only.set(!*bundles_only))
+    };
+
     let navigation_step = properties.navigation_step;
     let navigation_updated = {
         let date = date.clone();
@@ -83,7 +166,49 @@ pub fn StockTable<T: StockTableTrait>(properties: &StockTableProperties) -> Html
         })
     };
 
-    match T::get_stock(&properties.configuration, *date, &filter) {
+    let filtering: bool = !filter.is_empty() || *bundles_only;
+
+    // With a filter, exports the matching days as shown rather than a range of days.
+    let export_table = {
+        let configuration = properties.configuration.clone();
+        let date = *date;
+        let filter = (*filter).clone();
+        let completed_bundles = properties.completed_bundles.clone();
+        let bundles_only = *bundles_only;
+        Callback::from(move |count: u16| -> Result<ExportTable> {
+            if !filtering {
+                return export_stock::<T::Predictor>(
+                    T::NAME,
+                    &configuration,
+                    date..date + count as i32,
+                );
+            }
+
+            export_stock_matching::<T::Predictor>(
+                T::NAME,
+                &configuration,
+                date..date + FILTER_ITERATIONS as i32,
+                count as usize,
+                |stock_item: &StockItem| {
+                    stock_item_matches(
+                        stock_item,
+                        &filter,
+                        completed_bundles.as_deref(),
+                        bundles_only,
+                    )
+                },
+            )
+        })
+    };
+
+    match stock_table_rows::<T::Predictor>(
+        &properties.configuration,
+        *date,
+        &filter,
+        navigation_step as u16,
+        properties.completed_bundles.as_deref(),
+        *bundles_only,
+    ) {
         Ok(table) => {
             html!(
                 <>
@@ -93,12 +218,24 @@ pub fn StockTable<T: StockTableTrait>(properties: &StockTableProperties) -> Html
                             <DateJump updated={ date_jump_updated } />
                         </div>
                         <div class="column">
-                            <Filter updated={ filter_updated } />
+                            <Filter updated={ filter_updated } value={ (*filter).clone() } />
                         </div>
+                        {
+                            if properties.completed_bundles.is_some() {
+                                html!(
+                                    <div class="column is-narrow">
+                                        <Button updated={ bundles_only_updated } colour={ if *bundles_only { ButtonColour::Success } else { ButtonColour::Default } } label={ if *bundles_only { "Show all items" } else { "Only needed for bundles" } } />
+                                    </div>
+                                )
+                            } else {
+                                html!()
+                            }
+                        }
                     </div>
-                    <Navigation updated={ navigation_updated.clone() } disabled={ !filter.is_empty() } />
-                    <Table header={ stock_items_table_header() } body={ table } />
-                    <Navigation updated={ navigation_updated } disabled={ !filter.is_empty() } />
+                    <Navigation updated={ navigation_updated.clone() } disabled={ filtering } />
+                    <Table header={ stock_items_table_header(properties.completed_bundles.is_some()) } body={ table } />
+                    <Navigation updated={ navigation_updated } disabled={ filtering } />
+                    <Export visible={ if filtering { FILTER_DAYS as u16 } else { navigation_step as u16 } } table={ export_table } label={ if filtering { "Matching days to export (optional)" } else { "Days to export (optional)" } } />
                 </>
             )
         }
@@ -1,13 +1,20 @@
 use std::fmt::Display;
 use yew::prelude::*;
 
+use crate::predictors::util::ItemDetails;
+
 const SPRITE_HEIGHT: u8 = 16u8;
 
 #[derive(PartialEq)]
 pub enum TableValue {
     None,
     String(AttrValue),
+    /// A string that stands out, such as an item still needed for the museum.
+    Highlighted(AttrValue),
+    Checkbox(bool, Callback<()>),
     Sprite(AttrValue, u16, u16, u16, u16, u16, u16),
+    /// An item's sprite, showing its details when hovered or clicked.
+    Item(Box<TableValue>, ItemDetails),
 }
 
 impl TableValue {
@@ -15,13 +22,64 @@ impl TableValue {
         match self {
             TableValue::None => html!(),
             TableValue::String(string) => html!({ string }),
+            TableValue::Highlighted(string) => html!(
+                <strong class="has-text-success">{ string }</strong>
+            ),
+            TableValue::Checkbox(checked, updated) => {
+                let updated: Callback<()> = updated.clone();
+                html!(
+                    <input type="checkbox" checked={ *checked } onchange={ move |_: Event| updated.emit(()) } />
+                )
+            }
             TableValue::Sprite(image, x, y, width, height, sheet_width, sheet_height) => html!(
                 <figure class="image" style={ format!("background: url(./assets/{image}) calc(-{x}px * ({SPRITE_HEIGHT} / {height})) calc(-{y}px * ({SPRITE_HEIGHT} / {height})) / calc({sheet_width}px * ({SPRITE_HEIGHT} / {height})) calc({sheet_height}px * ({SPRITE_HEIGHT} / {height})); width: calc({width}px * ({SPRITE_HEIGHT} / {height})); height: calc({height}px * ({SPRITE_HEIGHT} / {height})); image-rendering: pixelated; display: inline-block;") } />
             ),
+            TableValue::Item(sprite, item_details) => html!(
+                <span title={ tooltip(item_details) }>{ sprite.to_html() }</span>
+            ),
         }
     }
 }
 
+fn tooltip(item_details: &ItemDetails) -> String {
+    match &item_details.description {
+        Some(description) => format!(
+            "{} ({})\n{}",
+            item_details.display_name, item_details.category, description
+        ),
+        None => format!("{} ({})", item_details.display_name, item_details.category),
+    }
+}
+
+fn item_details_html(item_details: &ItemDetails, closed: Callback<MouseEvent>) -> Html {
+    html!(
+        <div class="notification is-info is-light">
+            <button class="delete" onclick={ closed } />
+            <p><strong>{ item_details.display_name.clone() }</strong>{ format!(" ({})", item_details.category) }</p>
+            {
+                match &item_details.description {
+                    Some(description) => html!(<p>{ description.clone() }</p>),
+                    None => html!(),
+                }
+            }
+            {
+                match item_details.price {
+                    Some(price) => html!(<p>{ format!("Price: {}g", price) }</p>),
+                    None => html!(),
+                }
+            }
+            {
+                match (item_details.edibility, item_details.energy(), item_details.health()) {
+                    (Some(edibility), Some(energy), Some(health)) => html!(
+                        <p>{ format!("Edibility: {} ({} energy, {} health)", edibility, energy, health) }</p>
+                    ),
+                    _ => html!(<p>{ "Inedible" }</p>),
+                }
+            }
+        </div>
+    )
+}
+
 #[derive(PartialEq)]
 pub enum TableAlign {
     TopLeft,
@@ -71,28 +129,56 @@ pub struct TableProperties {
 
 #[component]
 pub fn Table(properties: &TableProperties) -> Html {
+    let selected = use_state_eq(|| None::<ItemDetails>);
+
+    let closed = {
+        let selected = selected.clone();
+        Callback::from(move |_: MouseEvent| selected.set(None))
+    };
+
+    let cell_html = |value: &TableValue| -> Html {
+        match value {
+            TableValue::Item(_, item_details) => {
+                let selected = selected.clone();
+                let item_details: ItemDetails = item_details.clone();
+                let clicked =
+                    Callback::from(move |_: MouseEvent| selected.set(Some(item_details.clone())));
+                html!(<a onclick={ clicked }>{ value.to_html() }</a>)
+            }
+            _ => value.to_html(),
+        }
+    };
+
     html!(
-        <div style="overflow-x: scroll;">
-            <table class="table is-fullwidth">
-                <thead>
-                    { for properties.header.iter().map(|row| html!(
-                        <tr>
-                            { for row.iter().map(|cell| html!(
-                                <th style={ cell.align.to_string() } rowspan={ cell.rows.to_string() } colspan={ cell.columns.to_string() }>{ cell.value.to_html() }</th>
-                            ))}
-                        </tr>
-                    ))}
-                </thead>
-                <tbody>
-                    { for properties.body.iter().map(|row| html!(
-                        <tr>
-                            { for row.iter().map(|cell| html!(
-                                <td style={ cell.align.to_string() } rowspan={ cell.rows.to_string() } colspan={ cell.columns.to_string() }>{ cell.value.to_html() }</td>
-                            ))}
-                        </tr>
-                    ))}
-                </tbody>
-            </table>
-        </div>
+        <>
+            {
+                match &*selected {
+                    Some(item_details) => item_details_html(item_details, closed),
+                    None => html!(),
+                }
+            }
+            <div style="overflow-x: scroll;">
+                <table class="table is-fullwidth">
+                    <thead>
+                        { for properties.header.iter().map(|row| html!(
+                            <tr>
+                                { for row.iter().map(|cell| html!(
+                                    <th style={ cell.align.to_string() } rowspan={ cell.rows.to_string() } colspan={ cell.columns.to_string() }>{ cell.value.to_html() }</th>
+                                ))}
+                            </tr>
+                        ))}
+                    </thead>
+                    <tbody>
+                        { for properties.body.iter().map(|row| html!(
+                            <tr>
+                                { for row.iter().map(|cell| html!(
+                                    <td style={ cell.align.to_string() } rowspan={ cell.rows.to_string() } colspan={ cell.columns.to_string() }>{ cell_html(&cell.value) }</td>
+                                ))}
+                            </tr>
+                        ))}
+                    </tbody>
+                </table>
+            </div>
+        </>
     )
 }
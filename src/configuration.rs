@@ -1,4 +1,7 @@
 use std::fmt::Display;
+use std::str::FromStr;
+
+use anyhow::{bail, Error, Result};
 
 #[derive(Clone, Copy, PartialEq)]
 pub enum Platform {
@@ -19,6 +22,18 @@ impl Display for Platform {
     }
 }
 
+impl FromStr for Platform {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s.to_lowercase().as_str() {
+            "pc" => Platform::PC,
+            "switch" => Platform::Switch,
+            _ => bail!("Unknown platform {}.", s),
+        })
+    }
+}
+
 #[derive(Clone, PartialEq)]
 pub struct Configuration {
     pub platform: Platform,
@@ -28,4 +43,15 @@ pub struct Configuration {
     pub mine_level: Option<u8>,
     pub qis_crop: Option<bool>,
     pub golden_helmet: Option<bool>,
+    /// The "guarantee year 1 completable" game option.
+    pub year_one_completable: Option<bool>,
+    /// Traveling cart visits left until the year 1 completable guarantee as of the date, as the
+    /// save records it. Without it, the count from the start of the game is assumed.
+    pub visits_until_y1_guarantee: Option<i32>,
+    /// The player's `UniqueMultiplayerID`, which seeds their nightly events.
+    pub player_id: Option<i64>,
+    /// The total steps taken so far today, which seeds the next day's luck.
+    pub steps_taken: Option<u32>,
+    /// The total trash cans checked so far, which decides whether the garbage hat can be found.
+    pub trash_cans_checked: Option<u32>,
 }
@@ -0,0 +1,39 @@
+use anyhow::Result;
+
+use crate::storage::LocalStorage;
+
+// Donations are stored per seed, as comma separated IDs.
+const DONATIONS: LocalStorage<Vec<u16>> = LocalStorage {
+    prefix: "museum:",
+    description: "museum donations",
+    serialise: |donations: &Vec<u16>| {
+        Some(
+            donations
+                .iter()
+                .map(|id: &u16| id.to_string())
+                .collect::<Vec<String>>()
+                .join(","),
+        )
+    },
+    deserialise: |donations: &str| {
+        Some(
+            donations
+                .split(',')
+                .filter_map(|id: &str| id.parse::<u16>().ok())
+                .collect(),
+        )
+    },
+};
+
+/// Returns no donations if none are stored for the seed.
+pub fn load_donations(seed: i32) -> Vec<u16> {
+    DONATIONS
+        .load(&seed.to_string())
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+}
+
+pub fn save_donations(seed: i32, donations: &[u16]) -> Result<()> {
+    DONATIONS.save(&seed.to_string(), &donations.to_vec())
+}
@@ -0,0 +1,445 @@
+use std::fmt::{Display, Write};
+use std::ops::Range;
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Error, Result};
+
+use crate::configuration::Configuration;
+use crate::predictors::farm_events::get_farm_event;
+use crate::predictors::geodes::{get_geodes, GEODE_TYPES};
+use crate::predictors::luck::{get_daily_luck, SpiritsMood};
+use crate::predictors::mines::{get_mine_floors, MINES_BOTTOM};
+use crate::predictors::trash_cans::{get_trash_cans_loot, TrashFind};
+use crate::predictors::util::{format_date, StockItem, StockPredictor};
+use crate::predictors::weather::{get_weather_chances, WeatherChances};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Json => "json",
+        }
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv",
+            Self::Json => "application/json",
+        }
+    }
+}
+
+impl Display for ExportFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Csv => "CSV",
+                Self::Json => "JSON",
+            }
+        )
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "csv" => Ok(Self::Csv),
+            "json" => Ok(Self::Json),
+            _ => bail!("Unknown export format {}.", s),
+        }
+    }
+}
+
+/// Kept typed so that numbers stay numbers in JSON.
+#[derive(Clone, PartialEq)]
+pub enum ExportValue {
+    None,
+    Bool(bool),
+    Integer(i64),
+    Float(f64),
+    String(String),
+}
+
+impl ExportValue {
+    fn to_csv(&self) -> String {
+        match self {
+            Self::None => String::new(),
+            Self::Bool(value) => value.to_string(),
+            Self::Integer(value) => value.to_string(),
+            Self::Float(value) => value.to_string(),
+            Self::String(value) => {
+                if value.contains([',', '"', '\n', '\r']) {
+                    format!("\"{}\"", value.replace('"', "\"\""))
+                } else {
+                    value.clone()
+                }
+            }
+        }
+    }
+
+    fn to_json(&self) -> String {
+        match self {
+            Self::None => "null".to_string(),
+            Self::Bool(value) => value.to_string(),
+            Self::Integer(value) => value.to_string(),
+            Self::Float(value) if value.is_finite() => value.to_string(),
+            Self::Float(_) => "null".to_string(),
+            Self::String(value) => json_string(value),
+        }
+    }
+}
+
+impl From<&str> for ExportValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<String> for ExportValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut json: String = String::from("\"");
+    for character in value.chars() {
+        match character {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            character if character.is_control() => {
+                write!(json, "\\u{:04x}", character as u32).unwrap();
+            }
+            character => json.push(character),
+        }
+    }
+    json.push('"');
+
+    json
+}
+
+/// A predictor's results, with one value per column in each row.
+#[derive(Clone, PartialEq)]
+pub struct ExportTable {
+    pub name: String,
+    pub columns: Vec<&'static str>,
+    pub rows: Vec<Vec<ExportValue>>,
+}
+
+impl ExportTable {
+    fn new(name: &str, columns: Vec<&'static str>) -> Self {
+        Self {
+            name: name.to_string(),
+            columns,
+            rows: Vec::new(),
+        }
+    }
+
+    /// A header line, then one line per row.
+    pub fn to_csv(&self) -> String {
+        let mut csv: String = self.columns.join(",");
+        csv.push('\n');
+        for row in &self.rows {
+            csv.push_str(
+                &row.iter()
+                    .map(ExportValue::to_csv)
+                    .collect::<Vec<String>>()
+                    .join(","),
+            );
+            csv.push('\n');
+        }
+
+        csv
+    }
+
+    /// An array with an object per row, keyed by column.
+    pub fn to_json(&self) -> String {
+        let rows: Vec<String> = self
+            .rows
+            .iter()
+            .map(|row: &Vec<ExportValue>| {
+                let fields: Vec<String> = self
+                    .columns
+                    .iter()
+                    .zip(row)
+                    .map(|(column, value)| format!("{}:{}", json_string(column), value.to_json()))
+                    .collect();
+                format!("{{{}}}", fields.join(","))
+            })
+            .collect();
+
+        format!("[{}]", rows.join(","))
+    }
+
+    pub fn export(&self, format: ExportFormat) -> String {
+        match format {
+            ExportFormat::Csv => self.to_csv(),
+            ExportFormat::Json => self.to_json(),
+        }
+    }
+
+    pub fn file_name(&self, format: ExportFormat) -> String {
+        format!(
+            "{}.{}",
+            self.name.to_lowercase().replace(' ', "-"),
+            format.extension()
+        )
+    }
+}
+
+/// CSV tables are separated by a blank line, JSON tables are keyed by name in an object.
+pub fn export_tables(tables: &[ExportTable], format: ExportFormat) -> String {
+    match format {
+        ExportFormat::Csv => tables
+            .iter()
+            .map(ExportTable::to_csv)
+            .collect::<Vec<String>>()
+            .join("\n"),
+        ExportFormat::Json => format!(
+            "{{{}}}",
+            tables
+                .iter()
+                .map(|table: &ExportTable| format!(
+                    "{}:{}",
+                    json_string(&table.name),
+                    table.to_json()
+                ))
+                .collect::<Vec<String>>()
+                .join(",")
+        ),
+    }
+}
+
+pub fn export_stock<T: StockPredictor>(
+    name: &str,
+    configuration: &Configuration,
+    dates: Range<i32>,
+) -> Result<ExportTable> {
+    export_stock_matching::<T>(name, configuration, dates, usize::MAX, |_: &StockItem| true)
+}
+
+/// The items `matches` keeps from the first `days` days in `dates` with one, as a filtered table
+/// shows them.
+pub fn export_stock_matching<T: StockPredictor>(
+    name: &str,
+    configuration: &Configuration,
+    dates: Range<i32>,
+    days: usize,
+    matches: impl Fn(&StockItem) -> bool,
+) -> Result<ExportTable> {
+    let mut table: ExportTable =
+        ExportTable::new(name, vec!["date", "day", "id", "item", "price", "quantity"]);
+    let mut days_exported: usize = 0usize;
+    for date in dates {
+        if days_exported >= days {
+            break;
+        }
+
+        let stock_items: Vec<StockItem> = match T::get_stock(configuration, date)? {
+            Some(stock_items) => stock_items,
+            None => continue,
+        };
+
+        let rows: usize = table.rows.len();
+        for stock_item in stock_items.iter().filter(|stock_item| matches(stock_item)) {
+            table.rows.push(vec![
+                ExportValue::from(format_date(date)),
+                ExportValue::Integer(date as i64),
+                ExportValue::Integer(stock_item.id as i64),
+                ExportValue::from(stock_item.item.name(stock_item.id)),
+                ExportValue::Integer(stock_item.price as i64),
+                ExportValue::Integer(stock_item.quantity as i64),
+            ]);
+        }
+        if table.rows.len() > rows {
+            days_exported += 1usize;
+        }
+    }
+
+    Ok(table)
+}
+
+pub fn export_geodes(
+    configuration: &Configuration,
+    geodes_cracked: Range<i32>,
+) -> Result<ExportTable> {
+    let mut table: ExportTable = ExportTable::new(
+        "Geodes",
+        vec!["geodes_cracked", "geode_type", "id", "item", "quantity"],
+    );
+    for geodes_cracked in geodes_cracked {
+        for (geode_type, geode) in GEODE_TYPES
+            .iter()
+            .zip(get_geodes(configuration, geodes_cracked)?)
+        {
+            table.rows.push(vec![
+                ExportValue::Integer(geodes_cracked as i64),
+                ExportValue::from(geode_type.to_string()),
+                ExportValue::Integer(geode.id as i64),
+                ExportValue::from(geode.item.name(geode.id)),
+                ExportValue::Integer(geode.quantity as i64),
+            ]);
+        }
+    }
+
+    Ok(table)
+}
+
+/// The next day's luck, like the luck tab, for each total of steps taken.
+pub fn export_luck(configuration: &Configuration, steps_taken: Range<u32>) -> Result<ExportTable> {
+    let date: i32 = configuration
+        .date
+        .unwrap_or(1i32)
+        .checked_add(1i32)
+        .context("Date is out of range.")?;
+    let mut table: ExportTable = ExportTable::new(
+        "Luck",
+        vec!["date", "day", "steps_taken", "luck", "spirits_mood"],
+    );
+    for steps_taken in steps_taken {
+        let luck: f64 = get_daily_luck(configuration, date, steps_taken)?;
+        table.rows.push(vec![
+            ExportValue::from(format_date(date)),
+            ExportValue::Integer(date as i64),
+            ExportValue::Integer(steps_taken as i64),
+            ExportValue::Float(luck),
+            ExportValue::from(SpiritsMood::from_luck(luck).to_string()),
+        ]);
+    }
+
+    Ok(table)
+}
+
+pub fn export_weather(dates: Range<i32>) -> Result<ExportTable> {
+    let mut table: ExportTable = ExportTable::new(
+        "Weather",
+        vec![
+            "date", "day", "sunny", "rain", "storm", "snow", "wind", "reason",
+        ],
+    );
+    for date in dates {
+        let weather_chances: WeatherChances = get_weather_chances(date);
+        table.rows.push(vec![
+            ExportValue::from(format_date(date)),
+            ExportValue::Integer(date as i64),
+            ExportValue::Float(weather_chances.sunny),
+            ExportValue::Float(weather_chances.rain),
+            ExportValue::Float(weather_chances.storm),
+            ExportValue::Float(weather_chances.snow),
+            ExportValue::Float(weather_chances.wind),
+            match weather_chances.reason {
+                Some(reason) => ExportValue::from(reason),
+                None => ExportValue::None,
+            },
+        ]);
+    }
+
+    Ok(table)
+}
+
+/// Only the nights with an event.
+pub fn export_farm_events(configuration: &Configuration, dates: Range<i32>) -> Result<ExportTable> {
+    let mut table: ExportTable =
+        ExportTable::new("Farm events", vec!["date", "day", "event", "requirement"]);
+    for date in dates {
+        if let Some(farm_event) = get_farm_event(configuration, date)? {
+            table.rows.push(vec![
+                ExportValue::from(format_date(date)),
+                ExportValue::Integer(date as i64),
+                ExportValue::from(farm_event.to_string()),
+                ExportValue::from(farm_event.requirement()),
+            ]);
+        }
+    }
+
+    Ok(table)
+}
+
+/// Only the floors that aren't normal.
+pub fn export_mines(configuration: &Configuration, dates: Range<i32>) -> Result<ExportTable> {
+    let mut table: ExportTable = ExportTable::new(
+        "Mines",
+        vec!["date", "day", "level", "infestation", "dark", "mushroom"],
+    );
+    for date in dates {
+        for floor in get_mine_floors(configuration, date, 1u8..=MINES_BOTTOM)? {
+            if floor.is_normal() {
+                continue;
+            }
+
+            table.rows.push(vec![
+                ExportValue::from(format_date(date)),
+                ExportValue::Integer(date as i64),
+                ExportValue::Integer(floor.level as i64),
+                match floor.infestation {
+                    Some(infestation) => ExportValue::from(infestation.to_string()),
+                    None => ExportValue::None,
+                },
+                ExportValue::Bool(floor.dark),
+                ExportValue::Bool(floor.mushroom),
+            ]);
+        }
+    }
+
+    Ok(table)
+}
+
+/// Only the trash cans with a find. A missing luck means any luck will do.
+pub fn export_trash_cans(configuration: &Configuration, dates: Range<i32>) -> Result<ExportTable> {
+    let find_values = |find: Option<TrashFind>| -> [ExportValue; 2usize] {
+        match find {
+            Some(find) => [
+                ExportValue::from(find.trash_item.name()),
+                match find.luck {
+                    Some(luck) => ExportValue::Float(luck),
+                    None => ExportValue::None,
+                },
+            ],
+            None => [ExportValue::None, ExportValue::None],
+        }
+    };
+
+    let mut table: ExportTable = ExportTable::new(
+        "Trash cans",
+        vec![
+            "date",
+            "day",
+            "trash_can",
+            "item",
+            "luck",
+            "special",
+            "special_luck",
+        ],
+    );
+    for date in dates {
+        for trash_can_loot in get_trash_cans_loot(configuration, date)? {
+            if trash_can_loot.find.is_none() {
+                continue;
+            }
+
+            let mut row: Vec<ExportValue> = vec![
+                ExportValue::from(format_date(date)),
+                ExportValue::Integer(date as i64),
+                ExportValue::from(trash_can_loot.trash_can.to_string()),
+            ];
+            row.extend(find_values(trash_can_loot.find));
+            row.extend(find_values(trash_can_loot.special));
+            table.rows.push(row);
+        }
+    }
+
+    Ok(table)
+}
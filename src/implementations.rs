@@ -1,7 +1,17 @@
+pub mod bundles;
+pub mod farm_events;
+pub mod geode_planner;
 pub mod geodes;
 pub mod joja;
 pub mod krobus;
+pub mod luck;
+pub mod mines;
+pub mod museum;
 pub mod pierre;
+pub mod saloon;
 pub mod sandy;
+pub mod search;
+pub mod trash_cans;
 pub mod traveling_cart;
 pub mod util;
+pub mod weather;
@@ -0,0 +1,219 @@
+use anyhow::{Context, Result};
+use yew::prelude::*;
+
+use crate::codegen::{Bundle, BundleItem, OBJECT_INFORMATION};
+use crate::components::button::{Button, ButtonColour};
+use crate::components::file_input::FileInput;
+use crate::components::message::{Message, MessageColour};
+use crate::components::table::{Table, TableAlign, TableCell, TableValue};
+use crate::predictors::bundles::{get_bundles, is_bundle_complete, BundleSlot};
+use crate::predictors::util::Item;
+use crate::save::bundles_from_save;
+
+fn string_cell(value: String, align: TableAlign, rows: u8) -> TableCell {
+    TableCell {
+        value: TableValue::String(AttrValue::from(value)),
+        align,
+        rows,
+        columns: 1u8,
+    }
+}
+
+fn quality_name(quality: u8) -> &'static str {
+    match quality {
+        1u8 => "Silver",
+        2u8 => "Gold",
+        4u8 => "Iridium",
+        _ => "",
+    }
+}
+
+fn bundles_table_header() -> Vec<Vec<TableCell>> {
+    vec![vec![
+        string_cell("Room".to_string(), TableAlign::MiddleLeft, 1u8),
+        string_cell("Bundle".to_string(), TableAlign::MiddleLeft, 1u8),
+        TableCell {
+            value: TableValue::String(AttrValue::from("Item")),
+            align: TableAlign::MiddleLeft,
+            rows: 1u8,
+            columns: 2u8,
+        },
+        string_cell("Quantity".to_string(), TableAlign::MiddleLeft, 1u8),
+        string_cell("Quality".to_string(), TableAlign::MiddleLeft, 1u8),
+        string_cell("Completed".to_string(), TableAlign::MiddleCenter, 1u8),
+    ]]
+}
+
+fn bundles_rows(
+    completed: &[BundleSlot],
+    completed_updated: &Callback<Vec<BundleSlot>>,
+) -> Result<Vec<Vec<TableCell>>> {
+    let mut table: Vec<Vec<TableCell>> = Vec::new();
+    for (index, bundle) in get_bundles() {
+        let bundle: &Bundle = bundle;
+        let complete: bool = is_bundle_complete(index, bundle, completed);
+
+        for (slot, bundle_item) in bundle.items.iter().enumerate() {
+            let bundle_item: &BundleItem = bundle_item;
+            let slot: u8 = slot as u8;
+            let is_completed: bool = completed.contains(&(index, slot));
+            let toggled: Callback<()> = {
+                let completed: Vec<BundleSlot> = completed.to_vec();
+                let completed_updated: Callback<Vec<BundleSlot>> = completed_updated.clone();
+                Callback::from(move |_: ()| {
+                    let mut completed: Vec<BundleSlot> = completed.clone();
+                    if is_completed {
+                        completed
+                            .retain(|completed_slot: &BundleSlot| *completed_slot != (index, slot));
+                    } else {
+                        completed.push((index, slot));
+                        completed.sort();
+                    }
+                    completed_updated.emit(completed);
+                })
+            };
+
+            let mut row: Vec<TableCell> = Vec::new();
+            if slot == 0u8 {
+                let rows: u8 = bundle.items.len() as u8;
+                row.push(string_cell(
+                    bundle.room.to_string(),
+                    TableAlign::MiddleLeft,
+                    rows,
+                ));
+                row.push(string_cell(
+                    format!(
+                        "{} ({} of {})",
+                        bundle.name,
+                        completed
+                            .iter()
+                            .filter(|(completed_index, _)| *completed_index == index)
+                            .count(),
+                        bundle.count
+                    ),
+                    TableAlign::MiddleLeft,
+                    rows,
+                ));
+            }
+
+            // The vault asks for gold rather than an item.
+            let (sprite, name): (TableValue, String) = if bundle_item.id < 0i32 {
+                (TableValue::None, format!("{}g", bundle_item.quantity))
+            } else {
+                let id: u16 = bundle_item.id as u16;
+                let item: Item = Item::ObjectInformation(
+                    OBJECT_INFORMATION
+                        .get(&id)
+                        .context("Error getting object information.")?,
+                );
+                (item.sprite(id), item.name(id))
+            };
+            row.push(TableCell {
+                value: sprite,
+                align: TableAlign::MiddleCenter,
+                rows: 1u8,
+                columns: 1u8,
+            });
+            row.push(TableCell {
+                value: if complete || is_completed {
+                    TableValue::String(AttrValue::from(name))
+                } else {
+                    TableValue::Highlighted(AttrValue::from(name))
+                },
+                align: TableAlign::MiddleLeft,
+                rows: 1u8,
+                columns: 1u8,
+            });
+            row.push(string_cell(
+                if bundle_item.id < 0i32 {
+                    String::new()
+                } else {
+                    format!("x{}", bundle_item.quantity)
+                },
+                TableAlign::MiddleLeft,
+                1u8,
+            ));
+            row.push(string_cell(
+                quality_name(bundle_item.quality).to_string(),
+                TableAlign::MiddleLeft,
+                1u8,
+            ));
+            row.push(TableCell {
+                value: TableValue::Checkbox(is_completed, toggled),
+                align: TableAlign::MiddleCenter,
+                rows: 1u8,
+                columns: 1u8,
+            });
+
+            table.push(row);
+        }
+    }
+
+    Ok(table)
+}
+
+#[derive(Properties, PartialEq)]
+pub struct BundlesProperties {
+    pub completed_bundles: Vec<BundleSlot>,
+    pub completed_bundles_updated: Callback<Vec<BundleSlot>>,
+}
+
+#[component]
+pub fn Bundles(properties: &BundlesProperties) -> Html {
+    let message = use_state_eq(|| None::<String>);
+
+    let save_updated = {
+        let message = message.clone();
+        let completed_bundles_updated = properties.completed_bundles_updated.clone();
+        Callback::from(move |save: Result<String>| {
+            match save.and_then(|save: String| bundles_from_save(&save)) {
+                Ok(completed_bundles) => {
+                    completed_bundles_updated.emit(completed_bundles);
+                    message.set(None);
+                }
+                Err(error) => {
+                    message.set(Some(error.to_string()));
+                }
+            }
+        })
+    };
+
+    let clear_updated = {
+        let completed_bundles_updated = properties.completed_bundles_updated.clone();
+        Callback::from(move |_: ()| completed_bundles_updated.emit(Vec::new()))
+    };
+
+    match bundles_rows(
+        &properties.completed_bundles,
+        &properties.completed_bundles_updated,
+    ) {
+        Ok(table) => {
+            html!(
+                <>
+                    <Message colour={ MessageColour::Info } body="Community Center bundle slots already completed, kept in this browser for each seed. Shop tabs flag the stock that open bundles still need, in a large enough quantity. Shops only sell normal quality items." />
+                    <Message colour={ MessageColour::Warning } body="Only the standard bundles are supported, not remixed ones." />
+                    {
+                        match (*message).clone() {
+                            Some(message) => html!(<Message colour={ MessageColour::Danger } body={ message } />),
+                            None => html!(),
+                        }
+                    }
+                    <div class="columns">
+                        <div class="column">
+                            <FileInput updated={ save_updated } label="Import from farm save file" />
+                        </div>
+                        <div class="column is-narrow">
+                            <Button updated={ clear_updated } colour={ ButtonColour::Danger } label="Clear completed slots" />
+                        </div>
+                    </div>
+                    <Table header={ bundles_table_header() } body={ table } />
+                </>
+            )
+        }
+        Err(error) => {
+            html! {
+                <Message colour={ MessageColour::Danger } body={ error.to_string() } />
+            }
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use anyhow::Result;
+use std::cmp::max;
+use yew::prelude::*;
+
+use crate::components::date::DateJump;
+use crate::components::export::Export;
+use crate::components::filter::Filter;
+use crate::components::message::{Message, MessageColour};
+use crate::components::navigation::{Navigation, NavigationDirection};
+use crate::components::table::{Table, TableAlign, TableCell, TableValue};
+use crate::configuration::Configuration;
+use crate::export::{export_farm_events, ExportTable};
+use crate::location::View;
+use crate::predictors::farm_events::{find_farm_events, FarmEvent};
+use crate::predictors::util::format_date;
+
+const NON_FILTER_ITERATIONS: i32 = 112i32;
+const FILTER_ITERATIONS: i32 = 11200i32;
+const FILTER_EVENTS: usize = 16usize;
+
+fn string_cell(value: String) -> TableCell {
+    TableCell {
+        value: TableValue::String(AttrValue::from(value)),
+        align: TableAlign::MiddleLeft,
+        rows: 1u8,
+        columns: 1u8,
+    }
+}
+
+fn farm_events_rows(
+    configuration: &Configuration,
+    date: i32,
+    filter: &str,
+) -> Result<Vec<Vec<TableCell>>> {
+    let farm_events: Vec<(i32, FarmEvent)> = if filter.is_empty() {
+        find_farm_events(
+            configuration,
+            date..date + NON_FILTER_ITERATIONS,
+            filter,
+            usize::MAX,
+        )?
+    } else {
+        find_farm_events(
+            configuration,
+            date..date + FILTER_ITERATIONS,
+            filter,
+            FILTER_EVENTS,
+        )?
+    };
+
+    Ok(farm_events
+        .into_iter()
+        .map(|(date, farm_event): (i32, FarmEvent)| {
+            vec![
+                string_cell(format_date(date)),
+                string_cell(farm_event.to_string()),
+                string_cell(farm_event.requirement().to_string()),
+            ]
+        })
+        .collect())
+}
+
+fn get_messages(configuration: &Configuration) -> Html {
+    html!(
+        <>
+            <Message colour={ MessageColour::Info } body="Events during the night before each date. Only nights with an event are listed, and the event can still fail to happen if its requirement isn't met. A wedding, or a community center or Joja completion the night before, replaces it." />
+            {
+                match configuration.player_id {
+                    Some(_) => html!(),
+                    None => html!(<Message colour={ MessageColour::Warning } body="Animal births are picked with a generator seeded by the player's ID. Use the player ID optional configuration parameter (or a save) to predict them." />),
+                }
+            }
+            {
+                match configuration.date {
+                    Some(_) => html!(),
+                    None => html!(<Message colour={ MessageColour::Warning } body="Use the date optional configuration parameter to always display from that date." />),
+                }
+            }
+        </>
+    )
+}
+
+pub fn farm_events_table_header() -> Vec<Vec<TableCell>> {
+    vec![["Date", "Event", "Note"]
+        .into_iter()
+        .map(|name: &str| string_cell(name.to_string()))
+        .collect()]
+}
+
+#[derive(Properties, PartialEq)]
+pub struct FarmEventsProperties {
+    pub configuration: Configuration,
+    pub view: View,
+    pub view_updated: Callback<View>,
+}
+
+#[component]
+pub fn FarmEvents(properties: &FarmEventsProperties) -> Html {
+    let date = use_state_eq(|| {
+        properties
+            .view
+            .position
+            .unwrap_or(properties.configuration.date.unwrap_or(1))
+    });
+    let filter = use_state_eq(|| properties.view.filter.clone());
+
+    {
+        let view_updated = properties.view_updated.clone();
+        use_effect_with((*date, (*filter).clone()), move |(date, filter)| {
+            view_updated.emit(View {
+                position: Some(*date),
+                filter: filter.clone(),
+            });
+        });
+    }
+
+    let date_jump_updated = {
+        let date = date.clone();
+        Callback::from(move |value: i32| date.set(value))
+    };
+
+    let filter_updated = {
+        let filter = filter.clone();
+        Callback::from(move |value: String| filter.set(value))
+    };
+
+    let navigation_updated = {
+        let date = date.clone();
+        Callback::from(move |direction: NavigationDirection| match direction {
+            NavigationDirection::Backward => {
+                date.set(max(*date - NON_FILTER_ITERATIONS, 1));
+            }
+            NavigationDirection::Forward => {
+                date.set(*date + NON_FILTER_ITERATIONS);
+            }
+        })
+    };
+
+    let export_table = {
+        let configuration = properties.configuration.clone();
+        let date = *date;
+        Callback::from(move |count: u16| -> Result<ExportTable> {
+            export_farm_events(&configuration, date..date + count as i32)
+        })
+    };
+
+    match farm_events_rows(&properties.configuration, *date, &filter) {
+        Ok(table) => {
+            html!(
+                <>
+                    { get_messages(&properties.configuration) }
+                    <div class="columns">
+                        <div class="column">
+                            <DateJump updated={ date_jump_updated } />
+                        </div>
+                        <div class="column">
+                            <Filter updated={ filter_updated } value={ (*filter).clone() } />
+                        </div>
+                    </div>
+                    <Navigation updated={ navigation_updated.clone() } disabled={ !filter.is_empty() } />
+                    <Table header={ farm_events_table_header() } body={ table } />
+                    <Navigation updated={ navigation_updated } disabled={ !filter.is_empty() } />
+                    <Export visible={ NON_FILTER_ITERATIONS as u16 } table={ export_table } label="Days to export (optional)" />
+                </>
+            )
+        }
+        Err(error) => {
+            html! {
+                <Message colour={ MessageColour::Danger } body={ error.to_string() } />
+            }
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use anyhow::Result;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::components::input::Input;
+use crate::components::message::{Message, MessageColour};
+use crate::components::table::{Table, TableAlign, TableCell, TableValue};
+use crate::configuration::Configuration;
+use crate::location::View;
+use crate::predictors::geode_planner::{plan_geodes, GeodePlan};
+use crate::predictors::geodes::{GeodeType, GEODE_TYPES};
+
+fn string_cell(value: String, columns: u8) -> TableCell {
+    TableCell {
+        value: TableValue::String(AttrValue::from(value)),
+        align: TableAlign::MiddleLeft,
+        rows: 1u8,
+        columns,
+    }
+}
+
+fn parse_wanted(wanted: &str) -> Vec<String> {
+    wanted
+        .split(',')
+        .map(|name: &str| name.trim().to_string())
+        .filter(|name: &String| !name.is_empty())
+        .collect()
+}
+
+fn geode_planner_rows(
+    configuration: &Configuration,
+    held: [u16; 6usize],
+    wanted: &[String],
+) -> Result<(Vec<Vec<TableCell>>, usize, bool)> {
+    let plan: GeodePlan = plan_geodes(configuration, held, wanted)?;
+
+    let mut found: Vec<String> = Vec::new();
+    let mut table: Vec<Vec<TableCell>> = Vec::new();
+    for planned_crack in plan.cracks {
+        let name: String = planned_crack.geode.item.name(planned_crack.geode.id);
+        if planned_crack.wanted && !found.contains(&name) {
+            found.push(name.clone());
+        }
+
+        table.push(vec![
+            string_cell(format!("{}", planned_crack.geodes_cracked), 1u8),
+            string_cell(planned_crack.geode_type.to_string(), 1u8),
+            TableCell {
+                value: planned_crack.geode.item.sprite(planned_crack.geode.id),
+                align: TableAlign::MiddleCenter,
+                rows: 1u8,
+                columns: 1u8,
+            },
+            string_cell(name, 1u8),
+            string_cell(format!("x{}", planned_crack.geode.quantity), 1u8),
+            string_cell(
+                if planned_crack.wanted { "Yes" } else { "" }.to_string(),
+                1u8,
+            ),
+        ]);
+    }
+
+    Ok((table, found.len(), plan.optimal))
+}
+
+fn get_messages(configuration: &Configuration) -> Html {
+    html!(
+        <>
+            <Message colour={ MessageColour::Info } body="Plans the order to crack the geodes held in that finds the most of the wanted items (comma separated names, e.g. Prismatic Shard, Fire Quartz). Only the first of each wanted item counts. The geodes that don't find one can be cracked in any order in between." />
+            {
+                match configuration.geodes_cracked {
+                    Some(_) => html!(),
+                    None => html!(<Message colour={ MessageColour::Warning } body="Use the geodes cracked optional configuration parameter to plan from there. We assume none by default." />),
+                }
+            }
+        </>
+    )
+}
+
+pub fn geode_planner_table_header() -> Vec<Vec<TableCell>> {
+    vec![vec![
+        string_cell("Cracked".to_string(), 1u8),
+        string_cell("Geode".to_string(), 1u8),
+        string_cell("Item".to_string(), 2u8),
+        string_cell("Quantity".to_string(), 1u8),
+        string_cell("Wanted".to_string(), 1u8),
+    ]]
+}
+
+#[derive(Properties, PartialEq)]
+pub struct GeodePlannerProperties {
+    pub configuration: Configuration,
+    pub view: View,
+    pub view_updated: Callback<View>,
+}
+
+#[component]
+pub fn GeodePlanner(properties: &GeodePlannerProperties) -> Html {
+    let held = use_state_eq(|| [0u16; 6usize]);
+    let wanted = use_state_eq(|| properties.view.filter.clone());
+    let planned = use_state_eq(|| None::<([u16; 6usize], String)>);
+
+    {
+        let view_updated = properties.view_updated.clone();
+        use_effect_with((*wanted).clone(), move |wanted| {
+            view_updated.emit(View {
+                position: None,
+                filter: wanted.clone(),
+            });
+        });
+    }
+
+    let wanted_updated = {
+        let wanted = wanted.clone();
+        Callback::from(move |event: InputEvent| {
+            wanted.set(event.target_unchecked_into::<HtmlInputElement>().value());
+        })
+    };
+
+    let plan_updated = {
+        let held = held.clone();
+        let wanted = wanted.clone();
+        let planned = planned.clone();
+        Callback::from(move |_: MouseEvent| planned.set(Some((*held, (*wanted).clone()))))
+    };
+
+    let inputs: Html = GEODE_TYPES
+        .iter()
+        .enumerate()
+        .map(|(index, geode_type): (usize, &GeodeType)| {
+            let held = held.clone();
+            let held_updated = Callback::from(move |value: Option<u16>| {
+                let mut updated: [u16; 6usize] = *held;
+                updated[index] = value.unwrap_or(0u16);
+                held.set(updated);
+            });
+
+            html!(
+                <div class="column">
+                    <Input<u16> updated={ held_updated } label={ format!("{} held", geode_type) } />
+                </div>
+            )
+        })
+        .collect();
+
+    html!(
+        <>
+            { get_messages(&properties.configuration) }
+            <div class="columns">
+                { inputs }
+            </div>
+            <div class="field has-addons">
+                <div class="control is-expanded">
+                    <input class="input" oninput={ wanted_updated } placeholder="Wanted items" type="text" value={ (*wanted).clone() } />
+                </div>
+                <div class="control">
+                    <button class="button is-primary" onclick={ plan_updated }>{ "Plan" }</button>
+                </div>
+            </div>
+            {
+                match &*planned {
+                    Some((held, wanted)) => {
+                        let wanted: Vec<String> = parse_wanted(wanted);
+                        match geode_planner_rows(&properties.configuration, *held, &wanted) {
+                            Ok((table, found, optimal)) => html!(
+                                <>
+                                    <Message colour={ MessageColour::Success } body={ format!("Finds {} of the {} wanted items.", found, wanted.len()) } />
+                                    {
+                                        match optimal {
+                                            true => html!(),
+                                            false => html!(<Message colour={ MessageColour::Warning } body="There are too many ways to find the wanted items to check them all, so this is the best plan found and may not be optimal. Try planning for fewer." />),
+                                        }
+                                    }
+                                    <Table header={ geode_planner_table_header() } body={ table } />
+                                </>
+                            ),
+                            Err(error) => html!(
+                                <Message colour={ MessageColour::Danger } body={ error.to_string() } />
+                            ),
+                        }
+                    }
+                    None => html!(),
+                }
+            }
+        </>
+    )
+}
@@ -1,33 +1,33 @@
-use crate::codegen::{ObjectInformation, ObjectInformationExtra, HATS, OBJECT_INFORMATION};
-use anyhow::{Context, Result};
+use anyhow::Result;
 use std::cmp::max;
 use yew::prelude::*;
 
+use crate::components::button::{Button, ButtonColour};
+use crate::components::export::Export;
 use crate::components::filter::Filter;
 use crate::components::jump::Jump;
 use crate::components::message::{Message, MessageColour};
 use crate::components::navigation::{Navigation, NavigationDirection};
 use crate::components::table::{Table, TableAlign, TableCell, TableValue};
 use crate::configuration::Configuration;
-use crate::implementations::util::{get_prng, Item};
-use crate::prng::Prng;
+use crate::export::{export_geodes, ExportTable};
+use crate::location::View;
+use crate::predictors::geodes::{get_geodes, Geode, GEODE_TYPES};
+use crate::predictors::museum::is_still_needed;
 
 const NON_FILTER_ITERATIONS: u16 = 28u16;
 const FILTER_ITERATIONS: u16 = 1120u16;
 const FILTER_GEODES: u8 = 8u8;
 
-struct Geode {
-    id: u16,
-    item: Item,
-    quantity: u8,
-}
-
-fn get_geodes(
+fn geodes_rows(
     configuration: &Configuration,
     geodes_cracked: i32,
-    filter: &String,
+    filter: &str,
+    donated: Option<&[u16]>,
+    needed_only: bool,
 ) -> Result<Vec<Vec<TableCell>>> {
-    let iterations: u16 = if filter.is_empty() {
+    let filtering: bool = !filter.is_empty() || needed_only;
+    let iterations: u16 = if !filtering {
         NON_FILTER_ITERATIONS
     } else {
         FILTER_ITERATIONS
@@ -37,211 +37,21 @@ fn get_geodes(
     for iteration in 0u16..iterations {
         let geodes_cracked: i32 = geodes_cracked + iteration as i32;
 
-        let mut geodes: [Option<Geode>; 6usize] = Default::default();
-
-        for i in 0usize..geodes.len() {
-            let mut prng: Box<dyn Prng> = get_prng(
-                configuration.platform,
-                configuration.seed / 2i32 + geodes_cracked,
-            )?;
-            for _ in 0i32..prng.gen_range(1i32..10i32)? {
-                prng.gen_float()?;
-            }
-            for _ in 0i32..prng.gen_range(1i32..10i32)? {
-                prng.gen_float()?;
-            }
-
-            geodes[i] = Some(
-                if prng.gen_float()? <= 0.1f64 && configuration.qis_crop.unwrap_or(false) {
-                    Geode {
-                        id: 890u16,
-                        item: Item::ObjectInformation(
-                            OBJECT_INFORMATION
-                                .get(&890u16)
-                                .context("Error getting object information.")?,
-                        ),
-                        quantity: if prng.gen_float()? < 0.25f64 {
-                            5u8
-                        } else {
-                            1u8
-                        },
-                    }
-                } else {
-                    match i {
-                        0usize..=4usize => {
-                            if i == 4usize || prng.gen_float()? >= 0.5f64 {
-                                // Artefact trove
-                                let object_information: &ObjectInformation = OBJECT_INFORMATION
-                                    .get(&match i {
-                                        0usize => 535u16,
-                                        1usize => 536u16,
-                                        2usize => 537u16,
-                                        3usize => 749u16,
-                                        4usize => 275u16,
-                                        _ => panic!(),
-                                    })
-                                    .unwrap();
-
-                                let treasure: &[u16] = match object_information.extra {
-                                    ObjectInformationExtra::Treasure(treasure) => treasure,
-                                    _ => panic!(),
-                                };
-
-                                let mut id: u16 =
-                                    treasure[prng.gen_range(0i32..treasure.len() as i32)? as usize];
-
-                                if i == 3usize
-                                    && prng.gen_float()? < 0.008f64
-                                    && geodes_cracked > 15i32
-                                {
-                                    // Omni geode
-                                    id = 74u16;
-                                }
-
-                                Geode {
-                                    id,
-                                    item: Item::ObjectInformation(
-                                        OBJECT_INFORMATION
-                                            .get(&id)
-                                            .context("Error getting object information.")?,
-                                    ),
-                                    quantity: 1u8,
-                                }
-                            } else {
-                                let mut quantity: u8 =
-                                    (prng.gen_range(0i32..3i32)? as u8) * 2u8 + 1u8;
-                                if prng.gen_float()? < 0.1f64 {
-                                    quantity = 10u8;
-                                }
-                                if prng.gen_float()? < 0.01f64 {
-                                    quantity = 20u8;
-                                }
-
-                                let (id, quantity): (u16, u8) = if prng.gen_float()? < 0.5f64 {
-                                    match prng.gen_range(0i32..4i32)? {
-                                        0i32 | 1i32 => (390u16, quantity),
-                                        2i32 => (330u16, 1u8),
-                                        3i32 => match i {
-                                            0usize => (86u16, 1u8), // Geode
-                                            1usize => (84u16, 1u8), // Frozen geode
-                                            2usize => (82u16, 1u8), // Magma geode
-                                            3usize => (
-                                                82u16 + (prng.gen_range(0i32..3i32)? as u16) * 2u16,
-                                                1u8,
-                                            ), // Omni geode
-                                            _ => panic!(),
-                                        },
-                                        _ => panic!(),
-                                    }
-                                } else {
-                                    match i {
-                                        0usize => match prng.gen_range(0i32..3i32)? {
-                                            // Geode
-                                            0i32 => (378u16, quantity),
-                                            1i32 => (
-                                                if configuration.mine_level.unwrap_or(120u8) > 25u8
-                                                {
-                                                    380u16
-                                                } else {
-                                                    378u16
-                                                },
-                                                quantity,
-                                            ),
-                                            2i32 => (382u16, quantity),
-                                            _ => panic!(),
-                                        },
-                                        1usize => match prng.gen_range(0i32..4i32)? {
-                                            // Frozen geode
-                                            0i32 => (378u16, quantity),
-                                            1i32 => (380u16, quantity),
-                                            2i32 => (382u16, quantity),
-                                            3i32 => (
-                                                if configuration.mine_level.unwrap_or(120u8) > 75u8
-                                                {
-                                                    384u16
-                                                } else {
-                                                    380u16
-                                                },
-                                                quantity,
-                                            ),
-                                            _ => panic!(),
-                                        },
-                                        2usize | 3usize => match prng.gen_range(0i32..5i32)? {
-                                            // Magma or omni geode
-                                            0i32 => (378u16, quantity),
-                                            1i32 => (380u16, quantity),
-                                            2i32 => (382u16, quantity),
-                                            3i32 => (384u16, quantity),
-                                            4i32 => (386u16, quantity / 2u8 + 1u8),
-                                            _ => panic!(),
-                                        },
-                                        _ => panic!(),
-                                    }
-                                };
-
-                                Geode {
-                                    id,
-                                    item: Item::ObjectInformation(
-                                        OBJECT_INFORMATION
-                                            .get(&id)
-                                            .context("Error getting object information.")?,
-                                    ),
-                                    quantity,
-                                }
-                            }
-                        }
-                        5usize => {
-                            // Golden coconut
-                            if prng.gen_float()? < 0.05f64
-                                && !configuration.golden_helmet.unwrap_or(true)
-                            {
-                                Geode {
-                                    id: 75u16,
-                                    item: Item::Hats(
-                                        HATS.get(&75u16).context("Error getting hats.")?,
-                                    ),
-                                    quantity: 1u8,
-                                }
-                            } else {
-                                let (id, quantity): (u16, u8) = match prng.gen_range(0i32..7i32)? {
-                                    0i32 => (69u16, 1u8),
-                                    1i32 => (835u16, 1u8),
-                                    2i32 => (833u16, 5u8),
-                                    3i32 => (831u16, 5u8),
-                                    4i32 => (820u16, 1u8),
-                                    5i32 => (292u16, 1u8),
-                                    6i32 => (386u16, 5u8),
-                                    _ => panic!(),
-                                };
-
-                                Geode {
-                                    id,
-                                    item: Item::ObjectInformation(
-                                        OBJECT_INFORMATION
-                                            .get(&id)
-                                            .context("Error getting object information.")?,
-                                    ),
-                                    quantity,
-                                }
-                            }
-                        }
-                        _ => panic!(),
-                    }
-                },
-            );
-        }
-
         // Filter after generating everything.
-        for i in 0usize..geodes.len() {
-            match &geodes[i] {
-                Some(geode) => {
-                    if !geode.item.name(geode.id).to_lowercase().contains(filter) {
-                        geodes[i] = None;
-                    }
+        let geodes: Vec<Option<Geode>> = get_geodes(configuration, geodes_cracked)?
+            .into_iter()
+            .map(|geode: Geode| {
+                let needed: bool = donated
+                    .is_some_and(|donated: &[u16]| is_still_needed(&geode.item, geode.id, donated));
+                if geode.item.name(geode.id).to_lowercase().contains(filter)
+                    && (needed || !needed_only)
+                {
+                    Some(geode)
+                } else {
+                    None
                 }
-                None => {}
-            }
-        }
+            })
+            .collect();
 
         if geodes.iter().any(|geode| geode.is_some()) {
             let mut row: Vec<TableCell> = vec![TableCell {
@@ -264,6 +74,13 @@ fn get_geodes(
 
                 row.push(TableCell {
                     value: match geode {
+                        Some(geode)
+                            if donated.is_some_and(|donated: &[u16]| {
+                                is_still_needed(&geode.item, geode.id, donated)
+                            }) =>
+                        {
+                            TableValue::Highlighted(AttrValue::from(geode.item.name(geode.id)))
+                        }
                         Some(geode) => {
                             TableValue::String(AttrValue::from(geode.item.name(geode.id)))
                         }
@@ -290,7 +107,7 @@ fn get_geodes(
             table.push(row);
 
             geodes_generated += 1u8;
-            if !filter.is_empty() && geodes_generated >= FILTER_GEODES {
+            if filtering && geodes_generated >= FILTER_GEODES {
                 break;
             }
         }
@@ -299,7 +116,7 @@ fn get_geodes(
     Ok(table)
 }
 
-fn get_messages(configuration: &Configuration) -> Html {
+fn get_messages(configuration: &Configuration, donated: bool) -> Html {
     let mut warnings: Vec<&str> = Vec::<&str>::new();
 
     if configuration.geodes_cracked.is_none() {
@@ -323,6 +140,13 @@ fn get_messages(configuration: &Configuration) -> Html {
     html!(
         <>
             <Message colour={ MessageColour::Info } body="Items from geodes." />
+            {
+                if donated {
+                    html!(<Message colour={ MessageColour::Info } body="Items still needed for the museum are highlighted. Mark donations in the museum tab." />)
+                } else {
+                    html!()
+                }
+            }
             {
                 warnings.into_iter().map(|warning|{
                     html!{
@@ -336,53 +160,26 @@ fn get_messages(configuration: &Configuration) -> Html {
 
 pub fn geodes_table_header() -> Vec<Vec<TableCell>> {
     vec![
-        vec![
-            TableCell {
+        {
+            let mut first_row: Vec<TableCell> = vec![TableCell {
                 value: TableValue::String(AttrValue::from("Cracked")),
                 align: TableAlign::MiddleLeft,
                 rows: 2u8,
                 columns: 1u8,
-            },
-            TableCell {
-                value: TableValue::String(AttrValue::from("Geode")),
-                align: TableAlign::MiddleLeft,
-                rows: 1u8,
-                columns: 3u8,
-            },
-            TableCell {
-                value: TableValue::String(AttrValue::from("Frozen Geode")),
-                align: TableAlign::MiddleLeft,
-                rows: 1u8,
-                columns: 3u8,
-            },
-            TableCell {
-                value: TableValue::String(AttrValue::from("Magma Geode")),
-                align: TableAlign::MiddleLeft,
-                rows: 1u8,
-                columns: 3u8,
-            },
-            TableCell {
-                value: TableValue::String(AttrValue::from("Omni Geode")),
-                align: TableAlign::MiddleLeft,
-                rows: 1u8,
-                columns: 3u8,
-            },
-            TableCell {
-                value: TableValue::String(AttrValue::from("Artifact Trove")),
-                align: TableAlign::MiddleLeft,
-                rows: 1u8,
-                columns: 3u8,
-            },
-            TableCell {
-                value: TableValue::String(AttrValue::from("Golden Coconut")),
-                align: TableAlign::MiddleLeft,
-                rows: 1u8,
-                columns: 3u8,
-            },
-        ],
+            }];
+            for geode_type in GEODE_TYPES {
+                first_row.push(TableCell {
+                    value: TableValue::String(AttrValue::from(geode_type.to_string())),
+                    align: TableAlign::MiddleLeft,
+                    rows: 1u8,
+                    columns: 3u8,
+                });
+            }
+            first_row
+        },
         {
             let mut second_row: Vec<TableCell> = Vec::new();
-            for _ in 0u8..6u8 {
+            for _ in GEODE_TYPES {
                 second_row.push(TableCell {
                     value: TableValue::String(AttrValue::from("Item")),
                     align: TableAlign::MiddleLeft,
@@ -404,12 +201,36 @@ pub fn geodes_table_header() -> Vec<Vec<TableCell>> {
 #[derive(Properties, PartialEq)]
 pub struct GeodesProperties {
     pub configuration: Configuration,
+    pub view: View,
+    pub view_updated: Callback<View>,
+    /// Museum donations, to highlight the items still needed.
+    #[prop_or_default]
+    pub donated: Option<Vec<u16>>,
 }
 
 #[component]
 pub fn Geodes(properties: &GeodesProperties) -> Html {
-    let geodes_cracked = use_state_eq(|| properties.configuration.geodes_cracked.unwrap_or(0u16) as i32);
-    let filter = use_state_eq(|| String::new());
+    let geodes_cracked = use_state_eq(|| {
+        properties
+            .view
+            .position
+            .unwrap_or(properties.configuration.geodes_cracked.unwrap_or(0u16) as i32)
+    });
+    let filter = use_state_eq(|| properties.view.filter.clone());
+    let needed_only = use_state_eq(|| false);
+
+    {
+        let view_updated = properties.view_updated.clone();
+        use_effect_with(
+            (*geodes_cracked, (*filter).clone()),
+            move |(geodes_cracked, filter)| {
+                view_updated.emit(View {
+                    position: Some(*geodes_cracked),
+                    filter: filter.clone(),
+                });
+            },
+        );
+    }
 
     let jump_updated = {
         let geodes_cracked = geodes_cracked.clone();
@@ -421,6 +242,11 @@ pub fn Geodes(properties: &GeodesProperties) -> Html {
         Callback::from(move |value: String| filter.set(value))
     };
 
+    let needed_only_updated = {
+        let needed_only = needed_only.clone();
+        Callback::from(move |_: ()| needed_only.set(!*needed_only))
+    };
+
     let navigation_updated = {
         let geodes_cracked = geodes_cracked.clone();
         Callback::from(move |direction: NavigationDirection| match direction {
@@ -433,22 +259,53 @@ pub fn Geodes(properties: &GeodesProperties) -> Html {
         })
     };
 
-    match get_geodes(&properties.configuration, *geodes_cracked, &filter) {
+    let export_table = {
+        let configuration = properties.configuration.clone();
+        let geodes_cracked = *geodes_cracked;
+        Callback::from(move |count: u16| -> Result<ExportTable> {
+            export_geodes(
+                &configuration,
+                geodes_cracked..geodes_cracked + count as i32,
+            )
+        })
+    };
+
+    let filtering: bool = !filter.is_empty() || *needed_only;
+
+    match geodes_rows(
+        &properties.configuration,
+        *geodes_cracked,
+        &filter,
+        properties.donated.as_deref(),
+        *needed_only,
+    ) {
         Ok(table) => {
             html!(
                 <>
-                    { get_messages(&properties.configuration) }
+                    { get_messages(&properties.configuration, properties.donated.is_some()) }
                     <div class="columns">
                         <div class="column">
                             <Jump<u16> updated={ jump_updated } />
                         </div>
                         <div class="column">
-                            <Filter updated={ filter_updated } />
+                            <Filter updated={ filter_updated } value={ (*filter).clone() } />
                         </div>
+                        {
+                            if properties.donated.is_some() {
+                                html!(
+                                    <div class="column is-narrow">
+                                        <Button updated={ needed_only_updated } colour={ if *needed_only { ButtonColour::Success } else { ButtonColour::Default } } label={ if *needed_only { "Show all items" } else { "Only still needed for the museum" } } />
+                                    </div>
+                                )
+                            } else {
+                                html!()
+                            }
+                        }
                     </div>
-                    <Navigation updated={ navigation_updated.clone() } disabled={ !filter.is_empty() } />
+                    <Navigation updated={ navigation_updated.clone() } disabled={ filtering } />
                     <Table header={ geodes_table_header() } body={ table } />
-                    <Navigation updated={ navigation_updated } disabled={ !filter.is_empty() } />
+                    <Navigation updated={ navigation_updated } disabled={ filtering } />
+                    <Export visible={ NON_FILTER_ITERATIONS } table={ export_table } label="Geodes to export (optional)" />
                 </>
             )
         }
@@ -0,0 +1,163 @@
+use anyhow::Result;
+use yew::prelude::*;
+
+use crate::components::export::Export;
+use crate::components::jump::Jump;
+use crate::components::message::{Message, MessageColour};
+use crate::components::navigation::{Navigation, NavigationDirection};
+use crate::components::table::{Table, TableAlign, TableCell, TableValue};
+use crate::configuration::Configuration;
+use crate::export::{export_luck, ExportTable};
+use crate::location::View;
+use crate::predictors::luck::{get_daily_luck, SpiritsMood};
+use crate::predictors::util::format_date;
+
+const NON_FILTER_ITERATIONS: u32 = 28u32;
+
+fn luck_rows(configuration: &Configuration, steps_taken: u32) -> Result<Vec<Vec<TableCell>>> {
+    let date: i32 = configuration.date.unwrap_or(1i32) + 1i32;
+
+    let mut table: Vec<Vec<TableCell>> = Vec::new();
+    for steps_taken in steps_taken..steps_taken.saturating_add(NON_FILTER_ITERATIONS) {
+        let luck: f64 = get_daily_luck(configuration, date, steps_taken)?;
+        table.push(vec![
+            TableCell {
+                value: TableValue::String(AttrValue::from(format!("{}", steps_taken))),
+                align: TableAlign::MiddleLeft,
+                rows: 1u8,
+                columns: 1u8,
+            },
+            TableCell {
+                value: TableValue::String(AttrValue::from(format!("{:.3}", luck))),
+                align: TableAlign::MiddleLeft,
+                rows: 1u8,
+                columns: 1u8,
+            },
+            TableCell {
+                value: TableValue::String(AttrValue::from(
+                    SpiritsMood::from_luck(luck).to_string(),
+                )),
+                align: TableAlign::MiddleLeft,
+                rows: 1u8,
+                columns: 1u8,
+            },
+        ]);
+    }
+
+    Ok(table)
+}
+
+fn get_messages(configuration: &Configuration) -> Html {
+    let mut warnings: Vec<&str> = Vec::<&str>::new();
+
+    if configuration.date.is_none() {
+        warnings.push("Use the date optional configuration parameter to predict the luck for the day after it. We assume the first day by default.");
+    }
+
+    if configuration.steps_taken.is_none() {
+        warnings.push(
+            "Use the steps taken optional configuration parameter to always display from there.",
+        );
+    }
+
+    html!(
+        <>
+            <Message colour={ MessageColour::Info } body={ format!("Daily luck on {}, by the total steps taken when going to bed the night before. The special charm adds 0.025 on top.", format_date(configuration.date.unwrap_or(1i32) + 1i32)) } />
+            {
+                warnings.into_iter().map(|warning|{
+                    html!{
+                        <Message colour={ MessageColour::Warning } body={ warning } />
+                    }
+                }).collect::<Html>()
+            }
+        </>
+    )
+}
+
+pub fn luck_table_header() -> Vec<Vec<TableCell>> {
+    vec![["Steps taken", "Luck", "Spirits' mood"]
+        .into_iter()
+        .map(|name: &str| TableCell {
+            value: TableValue::String(AttrValue::from(name)),
+            align: TableAlign::MiddleLeft,
+            rows: 1u8,
+            columns: 1u8,
+        })
+        .collect()]
+}
+
+#[derive(Properties, PartialEq)]
+pub struct LuckProperties {
+    pub configuration: Configuration,
+    pub view: View,
+    pub view_updated: Callback<View>,
+}
+
+#[component]
+pub fn Luck(properties: &LuckProperties) -> Html {
+    let steps_taken = use_state_eq(|| {
+        properties
+            .view
+            .position
+            .map(|position: i32| position.max(0i32) as u32)
+            .unwrap_or(properties.configuration.steps_taken.unwrap_or(0u32))
+    });
+
+    {
+        let view_updated = properties.view_updated.clone();
+        use_effect_with(*steps_taken, move |steps_taken| {
+            view_updated.emit(View {
+                position: Some(*steps_taken as i32),
+                filter: String::new(),
+            });
+        });
+    }
+
+    let jump_updated = {
+        let steps_taken = steps_taken.clone();
+        Callback::from(move |value: u32| steps_taken.set(value))
+    };
+
+    let navigation_updated = {
+        let steps_taken = steps_taken.clone();
+        Callback::from(move |direction: NavigationDirection| match direction {
+            NavigationDirection::Backward => {
+                steps_taken.set(steps_taken.saturating_sub(NON_FILTER_ITERATIONS));
+            }
+            NavigationDirection::Forward => {
+                steps_taken.set(steps_taken.saturating_add(NON_FILTER_ITERATIONS));
+            }
+        })
+    };
+
+    let export_table = {
+        let configuration = properties.configuration.clone();
+        let steps_taken = *steps_taken;
+        Callback::from(move |count: u16| -> Result<ExportTable> {
+            export_luck(
+                &configuration,
+                steps_taken..steps_taken.saturating_add(count as u32),
+            )
+        })
+    };
+
+    match luck_rows(&properties.configuration, *steps_taken) {
+        Ok(table) => {
+            html!(
+                <>
+                    { get_messages(&properties.configuration) }
+                    <Jump<u32> updated={ jump_updated } />
+                    <Navigation updated={ navigation_updated.clone() } disabled={ false } />
+                    <Table header={ luck_table_header() } body={ table } />
+                    <Navigation updated={ navigation_updated } disabled={ false } />
+                    <Export visible={ NON_FILTER_ITERATIONS as u16 } table={ export_table } label="Step totals to export (optional)" />
+                </>
+            )
+        }
+        Err(error) => {
+            html! {
+                <Message colour={ MessageColour::Danger } body={ error.to_string() } />
+            }
+        }
+    }
+}
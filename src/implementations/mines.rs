@@ -0,0 +1,181 @@
+use anyhow::Result;
+use std::cmp::max;
+use yew::prelude::*;
+
+use crate::components::date::DateJump;
+use crate::components::export::Export;
+use crate::components::message::{Message, MessageColour};
+use crate::components::navigation::{Navigation, NavigationDirection};
+use crate::components::table::{Table, TableAlign, TableCell, TableValue};
+use crate::configuration::Configuration;
+use crate::export::{export_mines, ExportTable};
+use crate::location::View;
+use crate::predictors::mines::{get_mine_floors, Infestation, MineFloor, MINES_BOTTOM};
+use crate::predictors::util::format_date;
+
+const NON_FILTER_ITERATIONS: i32 = 28i32;
+
+fn string_cell(value: String) -> TableCell {
+    TableCell {
+        value: TableValue::String(AttrValue::from(value)),
+        align: TableAlign::MiddleLeft,
+        rows: 1u8,
+        columns: 1u8,
+    }
+}
+
+/// Lists the levels of the floors matching `predicate`, with the ones deeper than the deepest level
+/// reached in brackets.
+fn levels_cell(
+    configuration: &Configuration,
+    floors: &[MineFloor],
+    predicate: impl Fn(&MineFloor) -> bool,
+) -> TableCell {
+    let deepest: u8 = configuration.mine_level.unwrap_or(MINES_BOTTOM);
+    string_cell(
+        floors
+            .iter()
+            .filter(|floor: &&MineFloor| predicate(floor))
+            .map(|floor: &MineFloor| {
+                if floor.level > deepest {
+                    format!("({})", floor.level)
+                } else {
+                    format!("{}", floor.level)
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(", "),
+    )
+}
+
+fn mines_rows(configuration: &Configuration, date: i32) -> Result<Vec<Vec<TableCell>>> {
+    let mut table: Vec<Vec<TableCell>> = Vec::new();
+    for date in date..date + NON_FILTER_ITERATIONS {
+        let floors: Vec<MineFloor> = get_mine_floors(configuration, date, 1u8..=MINES_BOTTOM)?;
+        table.push(vec![
+            string_cell(format_date(date)),
+            levels_cell(configuration, &floors, |floor: &MineFloor| {
+                floor.infestation == Some(Infestation::Monsters)
+            }),
+            levels_cell(configuration, &floors, |floor: &MineFloor| {
+                floor.infestation == Some(Infestation::Slimes)
+            }),
+            levels_cell(configuration, &floors, |floor: &MineFloor| floor.mushroom),
+            levels_cell(configuration, &floors, |floor: &MineFloor| floor.dark),
+        ]);
+    }
+
+    Ok(table)
+}
+
+fn get_messages(configuration: &Configuration) -> Html {
+    let mut warnings: Vec<&str> = Vec::<&str>::new();
+
+    if configuration.date.is_none() {
+        warnings.push(
+            "Use the date optional configuration parameter to always display from that date.",
+        );
+    }
+
+    if configuration.mine_level.is_none() {
+        warnings.push("The deepest mine level you have reached can be specified using the optional configuration parameter. We assume 120 by default.");
+    }
+
+    html!(
+        <>
+            <Message colour={ MessageColour::Info } body="Mine levels with modifiers on each day. Levels in brackets are deeper than the deepest level reached, so the elevator doesn't go there yet. Quarry floors and the Skull Cavern aren't included." />
+            {
+                warnings.into_iter().map(|warning|{
+                    html!{
+                        <Message colour={ MessageColour::Warning } body={ warning } />
+                    }
+                }).collect::<Html>()
+            }
+        </>
+    )
+}
+
+pub fn mines_table_header() -> Vec<Vec<TableCell>> {
+    vec![[
+        "Date",
+        "Monster infested",
+        "Slime infested",
+        "Mushroom",
+        "Dark",
+    ]
+    .into_iter()
+    .map(|name: &str| string_cell(name.to_string()))
+    .collect()]
+}
+
+#[derive(Properties, PartialEq)]
+pub struct MinesProperties {
+    pub configuration: Configuration,
+    pub view: View,
+    pub view_updated: Callback<View>,
+}
+
+#[component]
+pub fn Mines(properties: &MinesProperties) -> Html {
+    let date = use_state_eq(|| {
+        properties
+            .view
+            .position
+            .unwrap_or(properties.configuration.date.unwrap_or(1))
+    });
+
+    {
+        let view_updated = properties.view_updated.clone();
+        use_effect_with(*date, move |date| {
+            view_updated.emit(View {
+                position: Some(*date),
+                filter: String::new(),
+            });
+        });
+    }
+
+    let date_jump_updated = {
+        let date = date.clone();
+        Callback::from(move |value: i32| date.set(value))
+    };
+
+    let navigation_updated = {
+        let date = date.clone();
+        Callback::from(move |direction: NavigationDirection| match direction {
+            NavigationDirection::Backward => {
+                date.set(max(*date - NON_FILTER_ITERATIONS, 1));
+            }
+            NavigationDirection::Forward => {
+                date.set(*date + NON_FILTER_ITERATIONS);
+            }
+        })
+    };
+
+    let export_table = {
+        let configuration = properties.configuration.clone();
+        let date = *date;
+        Callback::from(move |count: u16| -> Result<ExportTable> {
+            export_mines(&configuration, date..date + count as i32)
+        })
+    };
+
+    match mines_rows(&properties.configuration, *date) {
+        Ok(table) => {
+            html!(
+                <>
+                    { get_messages(&properties.configuration) }
+                    <DateJump updated={ date_jump_updated } />
+                    <Navigation updated={ navigation_updated.clone() } disabled={ false } />
+                    <Table header={ mines_table_header() } body={ table } />
+                    <Navigation updated={ navigation_updated } disabled={ false } />
+                    <Export visible={ NON_FILTER_ITERATIONS as u16 } table={ export_table } label="Days to export (optional)" />
+                </>
+            )
+        }
+        Err(error) => {
+            html! {
+                <Message colour={ MessageColour::Danger } body={ error.to_string() } />
+            }
+        }
+    }
+}
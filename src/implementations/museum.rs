@@ -0,0 +1,191 @@
+use anyhow::{Context, Result};
+use yew::prelude::*;
+
+use crate::codegen::{ObjectInformation, OBJECT_INFORMATION};
+use crate::components::button::{Button, ButtonColour};
+use crate::components::file_input::FileInput;
+use crate::components::filter::Filter;
+use crate::components::message::{Message, MessageColour};
+use crate::components::table::{Table, TableAlign, TableCell, TableValue};
+use crate::location::View;
+use crate::predictors::museum::get_museum_items;
+use crate::predictors::util::Item;
+use crate::save::museum_from_save;
+
+fn museum_table_header() -> Vec<Vec<TableCell>> {
+    vec![vec![
+        TableCell {
+            value: TableValue::String(AttrValue::from("Item")),
+            align: TableAlign::MiddleLeft,
+            rows: 1u8,
+            columns: 2u8,
+        },
+        TableCell {
+            value: TableValue::String(AttrValue::from("Type")),
+            align: TableAlign::MiddleLeft,
+            rows: 1u8,
+            columns: 1u8,
+        },
+        TableCell {
+            value: TableValue::String(AttrValue::from("Donated")),
+            align: TableAlign::MiddleCenter,
+            rows: 1u8,
+            columns: 1u8,
+        },
+    ]]
+}
+
+fn museum_rows(
+    donated: &[u16],
+    filter: &str,
+    donated_updated: &Callback<Vec<u16>>,
+) -> Result<Vec<Vec<TableCell>>> {
+    let mut table: Vec<Vec<TableCell>> = Vec::new();
+    for id in get_museum_items() {
+        let object_information: &ObjectInformation = OBJECT_INFORMATION
+            .get(&id)
+            .context("Error getting object information.")?;
+        if !object_information.name.to_lowercase().contains(filter) {
+            continue;
+        }
+
+        let item: Item = Item::ObjectInformation(object_information);
+        let is_donated: bool = donated.contains(&id);
+        let toggled: Callback<()> = {
+            let donated: Vec<u16> = donated.to_vec();
+            let donated_updated: Callback<Vec<u16>> = donated_updated.clone();
+            Callback::from(move |_: ()| {
+                let mut donated: Vec<u16> = donated.clone();
+                if is_donated {
+                    donated.retain(|donated_id: &u16| *donated_id != id);
+                } else {
+                    donated.push(id);
+                    donated.sort();
+                }
+                donated_updated.emit(donated);
+            })
+        };
+
+        table.push(vec![
+            TableCell {
+                value: item.sprite(id),
+                align: TableAlign::MiddleCenter,
+                rows: 1u8,
+                columns: 1u8,
+            },
+            TableCell {
+                value: if is_donated {
+                    TableValue::String(AttrValue::from(item.name(id)))
+                } else {
+                    TableValue::Highlighted(AttrValue::from(item.name(id)))
+                },
+                align: TableAlign::MiddleLeft,
+                rows: 1u8,
+                columns: 1u8,
+            },
+            TableCell {
+                value: TableValue::String(AttrValue::from(
+                    if object_information.type_and_category.starts_with("Arch") {
+                        "Artifact"
+                    } else {
+                        "Mineral"
+                    },
+                )),
+                align: TableAlign::MiddleLeft,
+                rows: 1u8,
+                columns: 1u8,
+            },
+            TableCell {
+                value: TableValue::Checkbox(is_donated, toggled),
+                align: TableAlign::MiddleCenter,
+                rows: 1u8,
+                columns: 1u8,
+            },
+        ]);
+    }
+
+    Ok(table)
+}
+
+#[derive(Properties, PartialEq)]
+pub struct MuseumProperties {
+    pub view: View,
+    pub view_updated: Callback<View>,
+    pub donated: Vec<u16>,
+    pub donated_updated: Callback<Vec<u16>>,
+}
+
+#[component]
+pub fn Museum(properties: &MuseumProperties) -> Html {
+    let filter = use_state_eq(|| properties.view.filter.clone());
+    let message = use_state_eq(|| None::<String>);
+
+    {
+        let view_updated = properties.view_updated.clone();
+        use_effect_with((*filter).clone(), move |filter| {
+            view_updated.emit(View {
+                position: None,
+                filter: filter.clone(),
+            });
+        });
+    }
+
+    let filter_updated = {
+        let filter = filter.clone();
+        Callback::from(move |value: String| filter.set(value))
+    };
+
+    let save_updated = {
+        let message = message.clone();
+        let donated_updated = properties.donated_updated.clone();
+        Callback::from(move |save: Result<String>| {
+            match save.and_then(|save: String| museum_from_save(&save)) {
+                Ok(donated) => {
+                    donated_updated.emit(donated);
+                    message.set(None);
+                }
+                Err(error) => {
+                    message.set(Some(error.to_string()));
+                }
+            }
+        })
+    };
+
+    let clear_updated = {
+        let donated_updated = properties.donated_updated.clone();
+        Callback::from(move |_: ()| donated_updated.emit(Vec::new()))
+    };
+
+    match museum_rows(&properties.donated, &filter, &properties.donated_updated) {
+        Ok(table) => {
+            html!(
+                <>
+                    <Message colour={ MessageColour::Info } body="Minerals and artifacts donated to the museum, kept in this browser for each seed. Items still needed are highlighted in the geodes tab." />
+                    <Message colour={ MessageColour::Info } body="The traveling cart never sells minerals or artifacts, so it has nothing to highlight." />
+                    <Message colour={ MessageColour::Info } body={ format!("{} of {} items donated.", properties.donated.len(), get_museum_items().len()) } />
+                    {
+                        match (*message).clone() {
+                            Some(message) => html!(<Message colour={ MessageColour::Danger } body={ message } />),
+                            None => html!(),
+                        }
+                    }
+                    <div class="columns">
+                        <div class="column">
+                            <FileInput updated={ save_updated } label="Import from farm save file" />
+                        </div>
+                        <div class="column is-narrow">
+                            <Button updated={ clear_updated } colour={ ButtonColour::Danger } label="Clear donations" />
+                        </div>
+                    </div>
+                    <Filter updated={ filter_updated } value={ (*filter).clone() } />
+                    <Table header={ museum_table_header() } body={ table } />
+                </>
+            )
+        }
+        Err(error) => {
+            html! {
+                <Message colour={ MessageColour::Danger } body={ error.to_string() } />
+            }
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use yew::prelude::*;
+
+use crate::components::message::{Message, MessageColour};
+use crate::components::stock_table::{StockTable, StockTableTrait};
+use crate::configuration::Configuration;
+use crate::location::View;
+use crate::predictors::bundles::BundleSlot;
+use crate::predictors::saloon::SaloonPredictor;
+use crate::predictors::util::format_date;
+
+const NON_FILTER_ITERATIONS: u16 = 28u16;
+
+pub struct SaloonImpl {}
+
+impl StockTableTrait for SaloonImpl {
+    type Predictor = SaloonPredictor;
+    const NAME: &'static str = "Saloon";
+
+    fn get_messages(configuration: &Configuration) -> Html {
+        let mut warnings: Vec<&str> = Vec::<&str>::new();
+
+        if configuration.date.is_none() {
+            warnings.push(
+                "Use the date optional configuration parameter to always display from that date.",
+            );
+        }
+
+        if configuration.steps_taken.is_none() {
+            warnings.push("Use the steps taken optional configuration parameter to predict the dish for the next day. We assume no steps by default.");
+        }
+
+        html!(
+            <>
+                <Message colour={ MessageColour::Info } body={ format!("Dish of the day at the Stardrop Saloon. It is rolled overnight using the total steps taken when going to bed, so only {} is accurate, for the configured steps taken. Later days assume the same total.", format_date(configuration.date.unwrap_or(1i32) + 1i32)) } />
+                {
+                    warnings.into_iter().map(|warning|{
+                        html!{
+                            <Message colour={ MessageColour::Warning } body={ warning } />
+                        }
+                    }).collect::<Html>()
+                }
+            </>
+        )
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct SaloonProperties {
+    pub configuration: Configuration,
+    pub view: View,
+    pub view_updated: Callback<View>,
+    #[prop_or_default]
+    pub completed_bundles: Option<Vec<BundleSlot>>,
+}
+
+#[component]
+pub fn Saloon(properties: &SaloonProperties) -> Html {
+    html! {
+        <StockTable<SaloonImpl> configuration={ properties.configuration.clone() } navigation_step={ NON_FILTER_ITERATIONS as i32 } view={ properties.view.clone() } view_updated={ properties.view_updated.clone() } completed_bundles={ properties.completed_bundles.clone() } />
+    }
+}
@@ -0,0 +1,181 @@
+use anyhow::Result;
+use yew::prelude::*;
+
+use crate::components::filter::Filter;
+use crate::components::message::{Message, MessageColour};
+use crate::components::table::{Table, TableAlign, TableCell, TableValue};
+use crate::configuration::Configuration;
+use crate::implementations::util::Implementation;
+use crate::location::View;
+use crate::predictors::geodes::{find_geodes, Geode, GeodeType};
+use crate::predictors::joja::JojaPredictor;
+use crate::predictors::krobus::KrobusPredictor;
+use crate::predictors::pierre::PierrePredictor;
+use crate::predictors::sandy::SandyPredictor;
+use crate::predictors::traveling_cart::TravelingCartPredictor;
+use crate::predictors::util::{find_stock_items, format_date, StockItem, StockPredictor};
+
+const SEARCH_ITERATIONS: i32 = 1120i32;
+const SEARCH_RESULTS: usize = 16usize;
+
+fn string_cell(value: String, align: TableAlign) -> TableCell {
+    TableCell {
+        value: TableValue::String(AttrValue::from(value)),
+        align,
+        rows: 1u8,
+        columns: 1u8,
+    }
+}
+
+fn find_shop<T: StockPredictor>(
+    found: &mut Vec<(i32, Implementation, StockItem)>,
+    implementation: Implementation,
+    configuration: &Configuration,
+    filter: &str,
+) -> Result<()> {
+    let date: i32 = configuration.date.unwrap_or(1i32);
+    for (date, stock_item) in find_stock_items::<T>(
+        configuration,
+        date..date + SEARCH_ITERATIONS,
+        filter,
+        SEARCH_RESULTS,
+    )? {
+        found.push((date, implementation, stock_item));
+    }
+
+    Ok(())
+}
+
+fn shops_rows(configuration: &Configuration, filter: &str) -> Result<Vec<Vec<TableCell>>> {
+    let mut found: Vec<(i32, Implementation, StockItem)> = Vec::new();
+    find_shop::<TravelingCartPredictor>(
+        &mut found,
+        Implementation::TravelingCart,
+        configuration,
+        filter,
+    )?;
+    find_shop::<KrobusPredictor>(&mut found, Implementation::Krobus, configuration, filter)?;
+    find_shop::<SandyPredictor>(&mut found, Implementation::Sandy, configuration, filter)?;
+    find_shop::<PierrePredictor>(&mut found, Implementation::Pierre, configuration, filter)?;
+    find_shop::<JojaPredictor>(&mut found, Implementation::Joja, configuration, filter)?;
+
+    // The sort is stable, so shops on the same day stay in tab order.
+    found.sort_by_key(|(date, _, _)| *date);
+    found.truncate(SEARCH_RESULTS);
+
+    Ok(found
+        .into_iter()
+        .map(|(date, implementation, stock_item)| {
+            vec![
+                string_cell(format_date(date), TableAlign::MiddleLeft),
+                string_cell(implementation.to_string(), TableAlign::MiddleLeft),
+                TableCell {
+                    value: stock_item.item.sprite(stock_item.id),
+                    align: TableAlign::MiddleCenter,
+                    rows: 1u8,
+                    columns: 1u8,
+                },
+                string_cell(stock_item.item.name(stock_item.id), TableAlign::MiddleLeft),
+                string_cell(format!("{}g", stock_item.price), TableAlign::MiddleLeft),
+                string_cell(format!("x{}", stock_item.quantity), TableAlign::MiddleLeft),
+            ]
+        })
+        .collect())
+}
+
+fn geodes_rows(configuration: &Configuration, filter: &str) -> Result<Vec<Vec<TableCell>>> {
+    let geodes_cracked: i32 = configuration.geodes_cracked.unwrap_or(0u16) as i32;
+
+    Ok(find_geodes(
+        configuration,
+        geodes_cracked..geodes_cracked + SEARCH_ITERATIONS,
+        filter,
+        SEARCH_RESULTS,
+    )?
+    .into_iter()
+    .map(
+        |(geodes_cracked, geode_type, geode): (i32, GeodeType, Geode)| {
+            vec![
+                string_cell(format!("{}", geodes_cracked), TableAlign::MiddleCenter),
+                string_cell(geode_type.to_string(), TableAlign::MiddleLeft),
+                TableCell {
+                    value: geode.item.sprite(geode.id),
+                    align: TableAlign::MiddleCenter,
+                    rows: 1u8,
+                    columns: 1u8,
+                },
+                string_cell(geode.item.name(geode.id), TableAlign::MiddleLeft),
+                string_cell(format!("x{}", geode.quantity), TableAlign::MiddleLeft),
+            ]
+        },
+    )
+    .collect())
+}
+
+fn table_header(columns: &[(&str, u8)]) -> Vec<Vec<TableCell>> {
+    vec![columns
+        .iter()
+        .map(|(name, columns)| TableCell {
+            value: TableValue::String(AttrValue::from(name.to_string())),
+            align: TableAlign::MiddleLeft,
+            rows: 1u8,
+            columns: *columns,
+        })
+        .collect()]
+}
+
+#[derive(Properties, PartialEq)]
+pub struct SearchProperties {
+    pub configuration: Configuration,
+    pub view: View,
+    pub view_updated: Callback<View>,
+}
+
+#[component]
+pub fn Search(properties: &SearchProperties) -> Html {
+    let filter = use_state_eq(|| properties.view.filter.clone());
+
+    {
+        let view_updated = properties.view_updated.clone();
+        use_effect_with((*filter).clone(), move |filter| {
+            view_updated.emit(View {
+                position: None,
+                filter: filter.clone(),
+            });
+        });
+    }
+
+    let filter_updated = {
+        let filter = filter.clone();
+        Callback::from(move |value: String| filter.set(value))
+    };
+
+    let results: Html = if filter.is_empty() {
+        html!()
+    } else {
+        match (
+            shops_rows(&properties.configuration, &filter),
+            geodes_rows(&properties.configuration, &filter),
+        ) {
+            (Ok(shops), Ok(geodes)) => html!(
+                <>
+                    <h2 class="subtitle">{ "Shops" }</h2>
+                    <Table header={ table_header(&[("Date", 1u8), ("Shop", 1u8), ("Item", 2u8), ("Price", 1u8), ("Quantity", 1u8)]) } body={ shops } />
+                    <h2 class="subtitle">{ "Geodes" }</h2>
+                    <Table header={ table_header(&[("Cracked", 1u8), ("Geode", 1u8), ("Item", 2u8), ("Quantity", 1u8)]) } body={ geodes } />
+                </>
+            ),
+            (Err(error), _) | (_, Err(error)) => html!(
+                <Message colour={ MessageColour::Danger } body={ error.to_string() } />
+            ),
+        }
+    };
+
+    html!(
+        <>
+            <Message colour={ MessageColour::Info } body={ format!("The next {} times an item is sold by any shop, by date, and given by any geode, by geodes cracked. Searches from the configured date and geodes cracked.", SEARCH_RESULTS) } />
+            <Filter updated={ filter_updated } value={ (*filter).clone() } />
+            { results }
+        </>
+    )
+}
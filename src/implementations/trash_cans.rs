@@ -0,0 +1,234 @@
+use anyhow::Result;
+use std::cmp::max;
+use yew::prelude::*;
+
+use crate::components::date::DateJump;
+use crate::components::export::Export;
+use crate::components::message::{Message, MessageColour};
+use crate::components::navigation::{Navigation, NavigationDirection};
+use crate::components::table::{Table, TableAlign, TableCell, TableValue};
+use crate::configuration::Configuration;
+use crate::export::{export_trash_cans, ExportTable};
+use crate::location::View;
+use crate::predictors::trash_cans::{
+    get_trash_cans_loot, TrashCanLoot, TrashFind, TrashItem, TRASH_CANS,
+};
+use crate::predictors::util::format_date;
+
+const NON_FILTER_ITERATIONS: i32 = 28i32;
+
+fn format_luck(luck: Option<f64>) -> String {
+    match luck {
+        Some(luck) => format!("> {:.3}", luck),
+        None => "Any".to_string(),
+    }
+}
+
+fn trash_can_cells(trash_can_loot: &TrashCanLoot) -> [TableCell; 3usize] {
+    // The special find is shown when there is one, noting what is found with less luck.
+    let (shown, name): (Option<TrashFind>, String) =
+        match (trash_can_loot.find, trash_can_loot.special) {
+            (Some(find), Some(special)) => (
+                Some(special),
+                format!(
+                    "{} ({} below)",
+                    special.trash_item.name(),
+                    find.trash_item.name()
+                ),
+            ),
+            (Some(find), None) => (Some(find), find.trash_item.name()),
+            (None, _) => (None, String::new()),
+        };
+
+    [
+        TableCell {
+            value: match shown.map(|find: TrashFind| find.trash_item) {
+                Some(TrashItem::Item { id, item }) => item.sprite(id),
+                _ => TableValue::None,
+            },
+            align: TableAlign::MiddleCenter,
+            rows: 1u8,
+            columns: 1u8,
+        },
+        TableCell {
+            value: match shown {
+                Some(_) => TableValue::String(AttrValue::from(name)),
+                None => TableValue::None,
+            },
+            align: TableAlign::MiddleLeft,
+            rows: 1u8,
+            columns: 1u8,
+        },
+        TableCell {
+            value: match shown {
+                Some(find) => TableValue::String(AttrValue::from(format_luck(find.luck))),
+                None => TableValue::None,
+            },
+            align: TableAlign::MiddleLeft,
+            rows: 1u8,
+            columns: 1u8,
+        },
+    ]
+}
+
+fn trash_cans_rows(configuration: &Configuration, date: i32) -> Result<Vec<Vec<TableCell>>> {
+    let mut table: Vec<Vec<TableCell>> = Vec::new();
+    for date in date..date + NON_FILTER_ITERATIONS {
+        let mut row: Vec<TableCell> = vec![TableCell {
+            value: TableValue::String(AttrValue::from(format_date(date))),
+            align: TableAlign::MiddleLeft,
+            rows: 1u8,
+            columns: 1u8,
+        }];
+        for trash_can_loot in get_trash_cans_loot(configuration, date)? {
+            row.extend(trash_can_cells(&trash_can_loot));
+        }
+        table.push(row);
+    }
+
+    Ok(table)
+}
+
+fn get_messages(configuration: &Configuration) -> Html {
+    let mut warnings: Vec<&str> = Vec::<&str>::new();
+
+    if configuration.date.is_none() {
+        warnings.push(
+            "Use the date optional configuration parameter to always display from that date.",
+        );
+    }
+
+    if configuration.trash_cans_checked.is_none() {
+        warnings.push("The trash cans checked can be specified using the optional configuration parameter. We assume at least 20 by default, which allows the garbage hat.");
+    }
+
+    if configuration.steps_taken.is_none() {
+        warnings.push("The Saloon trash can can have the dish of the day, which is rolled using the steps taken optional configuration parameter. We assume no steps by default.");
+    }
+
+    html!(
+        <>
+            <Message colour={ MessageColour::Info } body="Items from the town's trash cans. Most need the daily luck (including the special charm) to be above the value shown. The Museum, Blacksmith, Saloon, Evelyn and George and JojaMart trash cans can replace the item with a special one, with the usual item found below the luck needed for it. JojaMart only gives Joja Cola before the community center is restored." />
+            <Message colour={ MessageColour::Info } body="Anyone nearby who sees you check a trash can loses 25 friendship, except Linus, who gains 5." />
+            {
+                warnings.into_iter().map(|warning|{
+                    html!{
+                        <Message colour={ MessageColour::Warning } body={ warning } />
+                    }
+                }).collect::<Html>()
+            }
+        </>
+    )
+}
+
+pub fn trash_cans_table_header() -> Vec<Vec<TableCell>> {
+    vec![
+        {
+            let mut first_row: Vec<TableCell> = vec![TableCell {
+                value: TableValue::String(AttrValue::from("Date")),
+                align: TableAlign::MiddleLeft,
+                rows: 2u8,
+                columns: 1u8,
+            }];
+            for trash_can in TRASH_CANS {
+                first_row.push(TableCell {
+                    value: TableValue::String(AttrValue::from(trash_can.to_string())),
+                    align: TableAlign::MiddleLeft,
+                    rows: 1u8,
+                    columns: 3u8,
+                });
+            }
+            first_row
+        },
+        {
+            let mut second_row: Vec<TableCell> = Vec::new();
+            for _ in TRASH_CANS {
+                second_row.push(TableCell {
+                    value: TableValue::String(AttrValue::from("Item")),
+                    align: TableAlign::MiddleLeft,
+                    rows: 1u8,
+                    columns: 2u8,
+                });
+                second_row.push(TableCell {
+                    value: TableValue::String(AttrValue::from("Luck")),
+                    align: TableAlign::MiddleLeft,
+                    rows: 1u8,
+                    columns: 1u8,
+                });
+            }
+            second_row
+        },
+    ]
+}
+
+#[derive(Properties, PartialEq)]
+pub struct TrashCansProperties {
+    pub configuration: Configuration,
+    pub view: View,
+    pub view_updated: Callback<View>,
+}
+
+#[component]
+pub fn TrashCans(properties: &TrashCansProperties) -> Html {
+    let date = use_state_eq(|| {
+        properties
+            .view
+            .position
+            .unwrap_or(properties.configuration.date.unwrap_or(1))
+    });
+
+    {
+        let view_updated = properties.view_updated.clone();
+        use_effect_with(*date, move |date| {
+            view_updated.emit(View {
+                position: Some(*date),
+                filter: String::new(),
+            });
+        });
+    }
+
+    let date_jump_updated = {
+        let date = date.clone();
+        Callback::from(move |value: i32| date.set(value))
+    };
+
+    let navigation_updated = {
+        let date = date.clone();
+        Callback::from(move |direction: NavigationDirection| match direction {
+            NavigationDirection::Backward => {
+                date.set(max(*date - NON_FILTER_ITERATIONS, 1));
+            }
+            NavigationDirection::Forward => {
+                date.set(*date + NON_FILTER_ITERATIONS);
+            }
+        })
+    };
+
+    let export_table = {
+        let configuration = properties.configuration.clone();
+        let date = *date;
+        Callback::from(move |count: u16| -> Result<ExportTable> {
+            export_trash_cans(&configuration, date..date + count as i32)
+        })
+    };
+
+    match trash_cans_rows(&properties.configuration, *date) {
+        Ok(table) => {
+            html!(
+                <>
+                    { get_messages(&properties.configuration) }
+                    <DateJump updated={ date_jump_updated } />
+                    <Navigation updated={ navigation_updated.clone() } disabled={ false } />
+                    <Table header={ trash_cans_table_header() } body={ table } />
+                    <Navigation updated={ navigation_updated } disabled={ false } />
+                    <Export visible={ NON_FILTER_ITERATIONS as u16 } table={ export_table } label="Days to export (optional)" />
+                </>
+            )
+        }
+        Err(error) => {
+            html! {
+                <Message colour={ MessageColour::Danger } body={ error.to_string() } />
+            }
+        }
+    }
+}
@@ -1,14 +1,12 @@
-use anyhow::{Context, Result};
 use std::fmt::Display;
+use std::str::FromStr;
+
+use anyhow::{bail, Error, Result};
 use yew::prelude::*;
 
-use crate::codegen::{
-    BigCraftablesInformation, ClothingInformation, Furniture, Hats, ObjectInformation,
-    CLOTHING_INFORMATION, FURNITURE, FURNITURE_OFF_LIMIT,
-};
 use crate::components::table::{TableAlign, TableCell, TableValue};
-use crate::configuration::Platform;
-use crate::prng::{Jkiss, MsCorLibRandom, Prng};
+use crate::predictors::bundles::{get_open_requirements, BundleSlot, OpenRequirement};
+use crate::predictors::util::{format_date, Item, StockItem};
 
 #[derive(Clone, Copy, PartialEq)]
 pub enum Implementation {
@@ -17,7 +15,17 @@ pub enum Implementation {
     Sandy,
     Pierre,
     Joja,
+    Saloon,
     Geodes,
+    GeodePlanner,
+    Museum,
+    Bundles,
+    Luck,
+    Weather,
+    FarmEvents,
+    Mines,
+    TrashCans,
+    Search,
 }
 
 impl Display for Implementation {
@@ -31,44 +39,63 @@ impl Display for Implementation {
                 Self::Sandy => "Sandy",
                 Self::Pierre => "Pierre",
                 Self::Joja => "Joja",
+                Self::Saloon => "Saloon",
                 Self::Geodes => "Geodes",
+                Self::GeodePlanner => "Geode planner",
+                Self::Museum => "Museum",
+                Self::Bundles => "Bundles",
+                Self::Luck => "Luck",
+                Self::Weather => "Weather",
+                Self::FarmEvents => "Farm events",
+                Self::Mines => "Mines",
+                Self::TrashCans => "Trash cans",
+                Self::Search => "Search",
             }
         )
     }
 }
 
-pub enum Item {
-    ObjectInformation(&'static ObjectInformation),
-    BigCraftablesInformation(&'static BigCraftablesInformation),
-    Furniture(&'static Furniture),
-    ClothingInformation(&'static ClothingInformation),
-    Wallpaper(bool),
-    Hats(&'static Hats),
-}
+pub const IMPLEMENTATIONS: [Implementation; 16usize] = [
+    Implementation::TravelingCart,
+    Implementation::Krobus,
+    Implementation::Sandy,
+    Implementation::Pierre,
+    Implementation::Joja,
+    Implementation::Saloon,
+    Implementation::Geodes,
+    Implementation::GeodePlanner,
+    Implementation::Museum,
+    Implementation::Bundles,
+    Implementation::Luck,
+    Implementation::Weather,
+    Implementation::FarmEvents,
+    Implementation::Mines,
+    Implementation::TrashCans,
+    Implementation::Search,
+];
 
-impl Item {
-    pub fn name(&self, id: u16) -> String {
-        match self {
-            Self::ObjectInformation(object_information) => object_information.name.to_string(),
-            Self::BigCraftablesInformation(big_craftable_information) => {
-                big_craftable_information.name.to_string()
-            }
-            Self::Furniture(furniture) => furniture.name.to_string(),
-            Self::ClothingInformation(clothing_information) => format!(
-                "{} ({})",
-                clothing_information.name,
-                if id >= 1000u16 { id - 1000u16 } else { id }
-            ),
-            Self::Wallpaper(flooring) => format!(
-                "{} ({})",
-                if *flooring { "Flooring" } else { "Wallpaper" },
-                id,
-            ),
-            Self::Hats(hats) => hats.name.to_string(),
+impl FromStr for Implementation {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match IMPLEMENTATIONS
+            .into_iter()
+            .find(|implementation: &Implementation| {
+                implementation.to_string().eq_ignore_ascii_case(s)
+            }) {
+            Some(implementation) => Ok(implementation),
+            None => bail!("Unknown implementation {}.", s),
         }
     }
+}
 
+impl Item {
+    /// The sprite, with the item's details.
     pub fn sprite(&self, id: u16) -> TableValue {
+        TableValue::Item(Box::new(self.plain_sprite(id)), self.details(id))
+    }
+
+    fn plain_sprite(&self, id: u16) -> TableValue {
         match self {
             Self::ObjectInformation(_) => TableValue::Sprite(
                 AttrValue::from("springobjects.png"),
@@ -133,7 +160,7 @@ impl Item {
                     560u16,
                 ),
             },
-            Self::Hats(hats) => TableValue::Sprite(
+            Self::Hats(_) => TableValue::Sprite(
                 AttrValue::from("hats.png"),
                 (id % 12u16) * 20u16,
                 (id / 12u16) * 20u16 * 4u16,
@@ -146,112 +173,112 @@ impl Item {
     }
 }
 
-pub struct StockItem {
-    pub id: u16,
-    pub item: Item,
-    pub price: u32,
-    pub quantity: u8,
-}
-
-pub fn get_prng(platform: Platform, seed: i32) -> Result<Box<dyn Prng>> {
-    Ok(match platform {
-        Platform::Switch => Box::new(Jkiss::from_seed(seed)?),
-        Platform::PC => Box::new(MsCorLibRandom::from_seed(seed)?),
-    })
-}
-
-pub fn day_number(date: i32) -> u8 {
-    ((date - 1i32) % 28i32) as u8
-}
-
-pub fn day_name(date: i32) -> &'static str {
-    match day_number(date) % 7u8 {
-        0u8 => "Monday",
-        1u8 => "Tuesday",
-        2u8 => "Wednesday",
-        3u8 => "Thursday",
-        4u8 => "Friday",
-        5u8 => "Saturday",
-        6u8 => "Sunday",
-        _ => panic!(),
-    }
-}
-
-pub fn season_number(date: i32) -> u8 {
-    (((date - 1i32) / 28i32) % 4i32) as u8
-}
-
-pub fn season_name(date: i32) -> &'static str {
-    match season_number(date) {
-        0u8 => "Spring",
-        1u8 => "Summer",
-        2u8 => "Fall",
-        3u8 => "Winter",
-        _ => panic!(),
-    }
-}
-
-pub fn year_number(date: i32) -> u32 {
-    ((date - 1i32) / 112i32) as u32
-}
-
-pub fn format_date(date: i32) -> String {
-    if date <= 0 {
-        return "UNEXPECTED".to_string();
-    }
-
-    format!(
-        "{} {} {}, Year {}",
-        day_name(date),
-        season_name(date),
-        day_number(date) + 1u8,
-        year_number(date) + 1u32
-    )
+/// Whether a filtered stock table shows the item.
+pub fn stock_item_matches(
+    stock_item: &StockItem,
+    filter: &str,
+    completed_bundles: Option<&[BundleSlot]>,
+    bundles_only: bool,
+) -> bool {
+    stock_item
+        .item
+        .name(stock_item.id)
+        .to_lowercase()
+        .contains(filter)
+        && (!bundles_only
+            || completed_bundles.is_some_and(|completed_bundles: &[BundleSlot]| {
+                !get_open_requirements(
+                    &stock_item.item,
+                    stock_item.id,
+                    stock_item.quantity,
+                    completed_bundles,
+                )
+                .is_empty()
+            }))
 }
 
 pub fn stock_items_rows(
-    stock_items: &Vec<StockItem>,
+    stock_items: &[StockItem],
     date: i32,
-    filter: &String,
+    filter: &str,
+    completed_bundles: Option<&[BundleSlot]>,
+    bundles_only: bool,
 ) -> Option<Vec<Vec<TableCell>>> {
     let mut rows: Vec<Vec<TableCell>> = stock_items
         .iter()
-        .filter(|stock_item: &&StockItem| {
-            stock_item
-                .item
-                .name(stock_item.id)
-                .to_lowercase()
-                .contains(filter)
-        })
-        .map(|stock_item: &StockItem| {
-            let mut row: Vec<TableCell> = Vec::new();
-            row.push(TableCell {
-                value: stock_item.item.sprite(stock_item.id),
-                align: TableAlign::MiddleCenter,
-                rows: 1u8,
-                columns: 1u8,
-            });
-            row.push(TableCell {
-                value: TableValue::String(AttrValue::from(stock_item.item.name(stock_item.id))),
-                align: TableAlign::MiddleLeft,
-                rows: 1u8,
-                columns: 1u8,
-            });
-            row.push(TableCell {
-                value: TableValue::String(AttrValue::from(format!("{}g", stock_item.price))),
-                align: TableAlign::MiddleLeft,
-                rows: 1u8,
-                columns: 1u8,
-            });
-            row.push(TableCell {
-                value: TableValue::String(AttrValue::from(format!("x{}", stock_item.quantity))),
-                align: TableAlign::MiddleLeft,
-                rows: 1u8,
-                columns: 1u8,
-            });
+        .filter_map(|stock_item: &StockItem| {
+            if !stock_item_matches(stock_item, filter, completed_bundles, bundles_only) {
+                return None;
+            }
 
-            row
+            let open_requirements: Vec<OpenRequirement> = match completed_bundles {
+                Some(completed_bundles) => get_open_requirements(
+                    &stock_item.item,
+                    stock_item.id,
+                    stock_item.quantity,
+                    completed_bundles,
+                ),
+                None => Vec::new(),
+            };
+            Some((stock_item, open_requirements))
         })
+        .map(
+            |(stock_item, open_requirements): (&StockItem, Vec<OpenRequirement>)| {
+                let mut row: Vec<TableCell> = Vec::new();
+                row.push(TableCell {
+                    value: stock_item.item.sprite(stock_item.id),
+                    align: TableAlign::MiddleCenter,
+                    rows: 1u8,
+                    columns: 1u8,
+                });
+                row.push(TableCell {
+                    value: if open_requirements.is_empty() {
+                        TableValue::String(AttrValue::from(stock_item.item.name(stock_item.id)))
+                    } else {
+                        TableValue::Highlighted(AttrValue::from(
+                            stock_item.item.name(stock_item.id),
+                        ))
+                    },
+                    align: TableAlign::MiddleLeft,
+                    rows: 1u8,
+                    columns: 1u8,
+                });
+                row.push(TableCell {
+                    value: TableValue::String(AttrValue::from(format!("{}g", stock_item.price))),
+                    align: TableAlign::MiddleLeft,
+                    rows: 1u8,
+                    columns: 1u8,
+                });
+                row.push(TableCell {
+                    value: TableValue::String(AttrValue::from(format!("x{}", stock_item.quantity))),
+                    align: TableAlign::MiddleLeft,
+                    rows: 1u8,
+                    columns: 1u8,
+                });
+                if completed_bundles.is_some() {
+                    row.push(TableCell {
+                        value: TableValue::Highlighted(AttrValue::from(
+                            open_requirements
+                                .iter()
+                                .map(|open_requirement: &OpenRequirement| {
+                                    format!(
+                                        "{} (x{})",
+                                        open_requirement.bundle.name,
+                                        open_requirement.bundle_item.quantity
+                                    )
+                                })
+                                .collect::<Vec<String>>()
+                                .join(", "),
+                        )),
+                        align: TableAlign::MiddleLeft,
+                        rows: 1u8,
+                        columns: 1u8,
+                    });
+                }
+
+                row
+            },
+        )
         .collect();
 
     let rows_length: u8 = rows.len() as u8;
@@ -272,34 +299,3 @@ pub fn stock_items_rows(
 
     Some(rows)
 }
-
-pub fn get_random_furniture(
-    prng: &mut Box<dyn Prng>,
-    lower_bound: u16,
-    upper_bound: u16,
-) -> Result<u16> {
-    loop {
-        let id: u16 = prng.gen_range((lower_bound as i32)..(upper_bound as i32))? as u16;
-        if !FURNITURE.contains_key(&id) || FURNITURE_OFF_LIMIT.contains(&id) {
-            continue;
-        }
-        return Ok(id);
-    }
-}
-
-pub fn get_clothing_information(id: u16) -> Result<&'static ClothingInformation> {
-    Ok(match CLOTHING_INFORMATION.get(&id) {
-        Some(item) => item,
-        None => {
-            if id >= 1000u16 {
-                CLOTHING_INFORMATION
-                    .get(&(u16::MAX - 2u16))
-                    .context("Error getting clothing information.")?
-            } else {
-                CLOTHING_INFORMATION
-                    .get(&(u16::MAX - 1u16))
-                    .context("Error getting clothing information.")?
-            }
-        }
-    })
-}
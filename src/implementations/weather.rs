@@ -0,0 +1,144 @@
+use std::cmp::max;
+
+use anyhow::Result;
+use yew::prelude::*;
+
+use crate::components::date::DateJump;
+use crate::components::export::Export;
+use crate::components::message::{Message, MessageColour};
+use crate::components::navigation::{Navigation, NavigationDirection};
+use crate::components::table::{Table, TableAlign, TableCell, TableValue};
+use crate::configuration::Configuration;
+use crate::export::{export_weather, ExportTable};
+use crate::location::View;
+use crate::predictors::util::format_date;
+use crate::predictors::weather::{get_weather_chances, WeatherChances};
+
+const NON_FILTER_ITERATIONS: i32 = 28i32;
+
+fn string_cell(value: String) -> TableCell {
+    TableCell {
+        value: TableValue::String(AttrValue::from(value)),
+        align: TableAlign::MiddleLeft,
+        rows: 1u8,
+        columns: 1u8,
+    }
+}
+
+fn format_chance(chance: f64) -> String {
+    if chance == 0f64 {
+        String::new()
+    } else {
+        format!("{:.1}%", chance * 100f64)
+    }
+}
+
+fn weather_rows(date: i32) -> Result<Vec<Vec<TableCell>>> {
+    let mut table: Vec<Vec<TableCell>> = Vec::new();
+    for date in date..date + NON_FILTER_ITERATIONS {
+        let weather_chances: WeatherChances = get_weather_chances(date);
+        table.push(vec![
+            string_cell(format_date(date)),
+            string_cell(format_chance(weather_chances.sunny)),
+            string_cell(format_chance(weather_chances.rain)),
+            string_cell(format_chance(weather_chances.storm)),
+            string_cell(format_chance(weather_chances.snow)),
+            string_cell(format_chance(weather_chances.wind)),
+            string_cell(weather_chances.reason.unwrap_or_default().to_string()),
+        ]);
+    }
+
+    Ok(table)
+}
+
+fn get_messages(configuration: &Configuration) -> Html {
+    html!(
+        <>
+            <Message colour={ MessageColour::Info } body="Chance of each weather. Festivals, the first day of each season and the third day are fixed. The rest is rolled the night before using the steps taken and other game state, so only the chances can be given. Wind can't follow rain or a storm, so after a day that isn't fixed, the chance of wind assumes that day's weather is independent of today's." />
+            {
+                match configuration.date {
+                    Some(_) => html!(),
+                    None => html!(<Message colour={ MessageColour::Warning } body="Use the date optional configuration parameter to always display from that date." />),
+                }
+            }
+        </>
+    )
+}
+
+pub fn weather_table_header() -> Vec<Vec<TableCell>> {
+    vec![["Date", "Sunny", "Rain", "Storm", "Snow", "Wind", "Note"]
+        .into_iter()
+        .map(|name: &str| string_cell(name.to_string()))
+        .collect()]
+}
+
+#[derive(Properties, PartialEq)]
+pub struct WeatherProperties {
+    pub configuration: Configuration,
+    pub view: View,
+    pub view_updated: Callback<View>,
+}
+
+#[component]
+pub fn Weather(properties: &WeatherProperties) -> Html {
+    let date = use_state_eq(|| {
+        properties
+            .view
+            .position
+            .unwrap_or(properties.configuration.date.unwrap_or(1))
+    });
+
+    {
+        let view_updated = properties.view_updated.clone();
+        use_effect_with(*date, move |date| {
+            view_updated.emit(View {
+                position: Some(*date),
+                filter: String::new(),
+            });
+        });
+    }
+
+    let date_jump_updated = {
+        let date = date.clone();
+        Callback::from(move |value: i32| date.set(value))
+    };
+
+    let navigation_updated = {
+        let date = date.clone();
+        Callback::from(move |direction: NavigationDirection| match direction {
+            NavigationDirection::Backward => {
+                date.set(max(*date - NON_FILTER_ITERATIONS, 1));
+            }
+            NavigationDirection::Forward => {
+                date.set(*date + NON_FILTER_ITERATIONS);
+            }
+        })
+    };
+
+    let export_table = {
+        let date = *date;
+        Callback::from(move |count: u16| -> Result<ExportTable> {
+            export_weather(date..date + count as i32)
+        })
+    };
+
+    match weather_rows(*date) {
+        Ok(table) => {
+            html!(
+                <>
+                    { get_messages(&properties.configuration) }
+                    <DateJump updated={ date_jump_updated } />
+                    <Navigation updated={ navigation_updated.clone() } disabled={ false } />
+                    <Table header={ weather_table_header() } body={ table } />
+                    <Navigation updated={ navigation_updated } disabled={ false } />
+                    <Export visible={ NON_FILTER_ITERATIONS as u16 } table={ export_table } label="Days to export (optional)" />
+                </>
+            )
+        }
+        Err(error) => {
+            html! {
+                <Message colour={ MessageColour::Danger } body={ error.to_string() } />
+            }
+        }
+    }
+}
@@ -1,6 +1,23 @@
+#[cfg(feature = "web")]
 pub mod app;
-mod codegen;
-mod components;
-mod configuration;
-mod implementations;
-mod prng;
+pub mod codegen;
+#[cfg(feature = "web")]
+pub mod completed_bundles;
+#[cfg(feature = "web")]
+pub mod components;
+pub mod configuration;
+#[cfg(feature = "web")]
+pub mod donations;
+pub mod export;
+#[cfg(feature = "web")]
+pub mod implementations;
+#[cfg(feature = "web")]
+pub mod location;
+pub mod predictors;
+pub mod prng;
+#[cfg(feature = "web")]
+pub mod profiles;
+pub mod save;
+pub mod seed_search;
+#[cfg(feature = "web")]
+pub mod storage;
@@ -0,0 +1,148 @@
+use std::str::FromStr;
+
+use gloo::utils::{history, window};
+use web_sys::wasm_bindgen::JsValue;
+use web_sys::UrlSearchParams;
+
+use crate::configuration::{Configuration, Platform};
+use crate::implementations::util::Implementation;
+
+/// Where a results table is, which is the date for shops and geodes cracked for geodes.
+#[derive(Clone, Default, PartialEq)]
+pub struct View {
+    pub position: Option<i32>,
+    pub filter: String,
+}
+
+/// Everything needed to share the page, stored in the URL query.
+#[derive(Clone, Default, PartialEq)]
+pub struct LocationState {
+    pub configuration: Option<Configuration>,
+    pub implementation: Option<Implementation>,
+    pub view: View,
+}
+
+fn get<T: FromStr>(parameters: &UrlSearchParams, name: &str) -> Option<T> {
+    parameters.get(name)?.parse::<T>().ok()
+}
+
+// The configuration needs at least a platform and seed, like the form.
+fn read_configuration(parameters: &UrlSearchParams) -> Option<Configuration> {
+    Some(Configuration {
+        platform: get::<Platform>(parameters, "platform")?,
+        seed: get::<i32>(parameters, "seed")?,
+        date: get::<i32>(parameters, "date"),
+        geodes_cracked: get::<u16>(parameters, "geodes"),
+        mine_level: get::<u8>(parameters, "mine"),
+        qis_crop: get::<bool>(parameters, "qi"),
+        golden_helmet: get::<bool>(parameters, "helmet"),
+        year_one_completable: get::<bool>(parameters, "y1"),
+        visits_until_y1_guarantee: get::<i32>(parameters, "y1visits"),
+        player_id: get::<i64>(parameters, "player"),
+        steps_taken: get::<u32>(parameters, "steps"),
+        trash_cans_checked: get::<u32>(parameters, "trash"),
+    })
+}
+
+fn append_configuration(parameters: &UrlSearchParams, configuration: &Configuration) {
+    parameters.append("platform", &configuration.platform.to_string());
+    parameters.append("seed", &configuration.seed.to_string());
+    if let Some(date) = configuration.date {
+        parameters.append("date", &date.to_string());
+    }
+    if let Some(geodes_cracked) = configuration.geodes_cracked {
+        parameters.append("geodes", &geodes_cracked.to_string());
+    }
+    if let Some(mine_level) = configuration.mine_level {
+        parameters.append("mine", &mine_level.to_string());
+    }
+    if let Some(qis_crop) = configuration.qis_crop {
+        parameters.append("qi", &qis_crop.to_string());
+    }
+    if let Some(golden_helmet) = configuration.golden_helmet {
+        parameters.append("helmet", &golden_helmet.to_string());
+    }
+    if let Some(year_one_completable) = configuration.year_one_completable {
+        parameters.append("y1", &year_one_completable.to_string());
+    }
+    if let Some(visits_until_y1_guarantee) = configuration.visits_until_y1_guarantee {
+        parameters.append("y1visits", &visits_until_y1_guarantee.to_string());
+    }
+    if let Some(player_id) = configuration.player_id {
+        parameters.append("player", &player_id.to_string());
+    }
+    if let Some(steps_taken) = configuration.steps_taken {
+        parameters.append("steps", &steps_taken.to_string());
+    }
+    if let Some(trash_cans_checked) = configuration.trash_cans_checked {
+        parameters.append("trash", &trash_cans_checked.to_string());
+    }
+}
+
+/// The configuration in the same form as the URL query, for storing elsewhere.
+pub fn configuration_to_query(configuration: &Configuration) -> Option<String> {
+    let parameters: UrlSearchParams = UrlSearchParams::new().ok()?;
+    append_configuration(&parameters, configuration);
+    Some(String::from(parameters.to_string()))
+}
+
+pub fn configuration_from_query(query: &str) -> Option<Configuration> {
+    read_configuration(&UrlSearchParams::new_with_str(query).ok()?)
+}
+
+impl LocationState {
+    pub fn read() -> Self {
+        let parameters: UrlSearchParams = match window()
+            .location()
+            .search()
+            .and_then(|search: String| UrlSearchParams::new_with_str(&search))
+        {
+            Ok(parameters) => parameters,
+            Err(_) => return Self::default(),
+        };
+
+        let configuration: Option<Configuration> = read_configuration(&parameters);
+
+        Self {
+            implementation: configuration
+                .as_ref()
+                .and(get::<Implementation>(&parameters, "tab")),
+            view: View {
+                position: get::<i32>(&parameters, "position"),
+                filter: parameters.get("filter").unwrap_or_default(),
+            },
+            configuration,
+        }
+    }
+
+    /// Replaces the current URL, so the back button isn't filled with every change.
+    pub fn write(&self) {
+        let parameters: UrlSearchParams = match UrlSearchParams::new() {
+            Ok(parameters) => parameters,
+            Err(_) => return,
+        };
+
+        if let Some(configuration) = &self.configuration {
+            append_configuration(&parameters, configuration);
+
+            if let Some(implementation) = self.implementation {
+                parameters.append("tab", &implementation.to_string());
+                if let Some(position) = self.view.position {
+                    parameters.append("position", &position.to_string());
+                }
+                if !self.view.filter.is_empty() {
+                    parameters.append("filter", &self.view.filter);
+                }
+            }
+        }
+
+        let search: String = String::from(parameters.to_string());
+        let pathname: String = window().location().pathname().unwrap_or_default();
+        let url: String = if search.is_empty() {
+            pathname
+        } else {
+            format!("{}?{}", pathname, search)
+        };
+        let _ = history().replace_state_with_url(&JsValue::NULL, "", Some(&url));
+    }
+}
@@ -0,0 +1,70 @@
+use crate::codegen::{Bundle, BundleItem, BUNDLES};
+use crate::predictors::util::Item;
+
+/// A bundle's index and the position of an item in it.
+pub type BundleSlot = (u16, u8);
+
+/// A bundle item that isn't donated yet, in a bundle that isn't complete.
+pub struct OpenRequirement {
+    pub index: u16,
+    pub bundle: &'static Bundle,
+    pub slot: u8,
+    pub bundle_item: &'static BundleItem,
+}
+
+/// Every bundle, in order of index.
+pub fn get_bundles() -> Vec<(u16, &'static Bundle)> {
+    let mut bundles: Vec<(u16, &'static Bundle)> = BUNDLES
+        .entries()
+        .map(|(index, bundle)| (*index, bundle))
+        .collect();
+    bundles.sort_by_key(|(index, _)| *index);
+
+    bundles
+}
+
+pub fn is_bundle_complete(index: u16, bundle: &Bundle, completed: &[BundleSlot]) -> bool {
+    completed
+        .iter()
+        .filter(|(completed_index, _)| *completed_index == index)
+        .count()
+        >= bundle.count as usize
+}
+
+/// The open requirements that `quantity` of a shop item satisfies. Shops only sell normal quality
+/// items, so requirements for a higher quality are never satisfied.
+pub fn get_open_requirements(
+    item: &Item,
+    id: u16,
+    quantity: u8,
+    completed: &[BundleSlot],
+) -> Vec<OpenRequirement> {
+    if !matches!(item, Item::ObjectInformation(_)) {
+        return Vec::new();
+    }
+
+    let mut open_requirements: Vec<OpenRequirement> = Vec::new();
+    for (index, bundle) in get_bundles() {
+        if is_bundle_complete(index, bundle, completed) {
+            continue;
+        }
+
+        for (slot, bundle_item) in bundle.items.iter().enumerate() {
+            let slot: u8 = slot as u8;
+            if bundle_item.id == id as i32
+                && bundle_item.quality == 0u8
+                && bundle_item.quantity <= quantity as u32
+                && !completed.contains(&(index, slot))
+            {
+                open_requirements.push(OpenRequirement {
+                    index,
+                    bundle,
+                    slot,
+                    bundle_item,
+                });
+            }
+        }
+    }
+
+    open_requirements
+}
@@ -0,0 +1,134 @@
+use std::fmt::Display;
+use std::ops::Range;
+
+use anyhow::Result;
+
+use crate::configuration::Configuration;
+use crate::predictors::util::{get_prng, season_number, year_number};
+use crate::prng::Prng;
+
+/// The nightly events from `Utility.pickFarmEvent`, and `Utility.pickPersonalFarmEvent` for the
+/// animal birth.
+#[derive(Clone, Copy, PartialEq)]
+pub enum FarmEvent {
+    Fairy,
+    Witch,
+    Meteorite,
+    StoneOwl,
+    StrangeCapsule,
+    Earthquake,
+    AnimalBirth,
+}
+
+impl FarmEvent {
+    /// What the event needs to actually happen, which the predictor doesn't know about.
+    pub fn requirement(&self) -> &'static str {
+        match self {
+            FarmEvent::Fairy => "Needs a crop on the farm, which is fully grown in the morning.",
+            FarmEvent::Witch => "Needs a coop or slime hutch.",
+            FarmEvent::Meteorite => "Needs a free 2x2 space on the farm.",
+            FarmEvent::StoneOwl => "Needs a free space on the farm.",
+            FarmEvent::StrangeCapsule => {
+                "Only if the capsule hasn't been found before, and needs a free space on the farm."
+            }
+            FarmEvent::Earthquake => "Opens the way to the railroad.",
+            FarmEvent::AnimalBirth => {
+                "Needs a barn that isn't full, then the chance is 0.55% for each animal in it. Only right if a spouse can't ask for a baby, which rolls first."
+            }
+        }
+    }
+}
+
+impl Display for FarmEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                FarmEvent::Fairy => "Crop fairy",
+                FarmEvent::Witch => "Witch",
+                FarmEvent::Meteorite => "Meteorite",
+                FarmEvent::StoneOwl => "Stone owl",
+                FarmEvent::StrangeCapsule => "Strange capsule",
+                FarmEvent::Earthquake => "Earthquake",
+                FarmEvent::AnimalBirth => "Animal birth",
+            }
+        )
+    }
+}
+
+/// The event during the night before `date`, picked using a PRNG seeded with `seed / 2 + date`.
+/// Weddings and the community center or Joja events the night before take priority over these.
+/// Without one, the player's own event is picked, which needs their ID.
+pub fn get_farm_event(configuration: &Configuration, date: i32) -> Result<Option<FarmEvent>> {
+    // Summer 3 of the first year.
+    if date == 31i32 {
+        return Ok(Some(FarmEvent::Earthquake));
+    }
+
+    let mut prng: Box<dyn Prng> = get_prng(
+        configuration.platform,
+        (configuration.seed / 2i32).wrapping_add(date),
+    )?;
+
+    if prng.gen_float()? < 0.01f64 && season_number(date) != 3u8 {
+        return Ok(Some(FarmEvent::Fairy));
+    }
+    // The days played are the date.
+    if prng.gen_float()? < 0.01f64 && date > 20i32 {
+        return Ok(Some(FarmEvent::Witch));
+    }
+    if prng.gen_float()? < 0.01f64 && date > 5i32 {
+        return Ok(Some(FarmEvent::Meteorite));
+    }
+    if prng.gen_float()? < 0.005f64 {
+        return Ok(Some(FarmEvent::StoneOwl));
+    }
+    if prng.gen_float()? < 0.008f64 && year_number(date) > 0u32 {
+        return Ok(Some(FarmEvent::StrangeCapsule));
+    }
+
+    let player_id: i64 = match configuration.player_id {
+        Some(player_id) => player_id,
+        None => return Ok(None),
+    };
+
+    // The game casts the 64-bit ID to an int, keeping the low 32 bits.
+    let mut prng: Box<dyn Prng> = get_prng(
+        configuration.platform,
+        (configuration.seed / 2i32)
+            .wrapping_add(date)
+            .wrapping_add(470124797i32)
+            .wrapping_add(player_id as i32),
+    )?;
+
+    // The other half is a sound in the night, which does nothing.
+    if prng.gen_float()? < 0.5f64 {
+        return Ok(Some(FarmEvent::AnimalBirth));
+    }
+
+    Ok(None)
+}
+
+/// The first `count` nights in `dates` with an event whose name contains `filter`.
+pub fn find_farm_events(
+    configuration: &Configuration,
+    dates: Range<i32>,
+    filter: &str,
+    count: usize,
+) -> Result<Vec<(i32, FarmEvent)>> {
+    let mut found: Vec<(i32, FarmEvent)> = Vec::new();
+    for date in dates {
+        if found.len() >= count {
+            break;
+        }
+
+        if let Some(farm_event) = get_farm_event(configuration, date)? {
+            if farm_event.to_string().to_lowercase().contains(filter) {
+                found.push((date, farm_event));
+            }
+        }
+    }
+
+    Ok(found)
+}
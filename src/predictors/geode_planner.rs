@@ -0,0 +1,174 @@
+use anyhow::{bail, Result};
+
+use crate::configuration::Configuration;
+use crate::predictors::geodes::{get_geodes, Geode, GeodeType, GEODE_TYPES};
+
+const MAX_GEODES: i32 = 10000i32;
+const MAX_WANTED: usize = 32usize;
+/// Bounds the search, which can grow exponentially with the wanted items.
+const MAX_SEARCH_STEPS: u32 = 1000000u32;
+
+pub struct PlannedCrack {
+    pub geodes_cracked: i32,
+    pub geode_type: GeodeType,
+    pub geode: Geode,
+    pub wanted: bool,
+}
+
+pub struct GeodePlan {
+    pub cracks: Vec<PlannedCrack>,
+    /// False if the search gave up, so a better order may exist.
+    pub optimal: bool,
+}
+
+/// Cracking a geode type at a crack (counted from the geodes cracked) that gives a wanted item.
+#[derive(Clone, Copy)]
+struct Candidate {
+    crack: usize,
+    geode_type: usize,
+}
+
+struct Search<'a> {
+    held: [u16; 6usize],
+    /// The candidates for each wanted item that can be found, fewest first.
+    candidates: &'a [Vec<Candidate>],
+    cracks_used: Vec<bool>,
+    geodes_used: [u16; 6usize],
+    chosen: Vec<Candidate>,
+    best: Vec<Candidate>,
+    steps: u32,
+}
+
+impl Search<'_> {
+    /// Keeps the best found so far in `best`, and returns false once out of steps.
+    fn search(&mut self, item: usize) -> bool {
+        self.steps += 1u32;
+        if self.steps > MAX_SEARCH_STEPS {
+            return false;
+        }
+
+        if self.chosen.len() > self.best.len() {
+            self.best = self.chosen.clone();
+        }
+        // Even finding every remaining item can't do better.
+        if item >= self.candidates.len()
+            || self.chosen.len() + self.candidates.len() - item <= self.best.len()
+        {
+            return true;
+        }
+
+        for candidate in self.candidates[item].iter() {
+            if self.cracks_used[candidate.crack]
+                || self.geodes_used[candidate.geode_type] >= self.held[candidate.geode_type]
+            {
+                continue;
+            }
+
+            self.cracks_used[candidate.crack] = true;
+            self.geodes_used[candidate.geode_type] += 1u16;
+            self.chosen.push(*candidate);
+            let finished: bool = self.search(item + 1usize);
+            self.chosen.pop();
+            self.geodes_used[candidate.geode_type] -= 1u16;
+            self.cracks_used[candidate.crack] = false;
+            if !finished {
+                return false;
+            }
+        }
+
+        // Skipping this item.
+        self.search(item + 1usize)
+    }
+}
+
+/// The order to crack `held` (in `GEODE_TYPES` order) in that finds the most distinct items from
+/// `wanted`. Each crack advances the geodes cracked, which reseeds every type, so only the cracks
+/// where some type gives a wanted item matter, and the other geodes fill the rest in any order.
+/// With too many ways to find the wanted items, the best order found within the search's bound is
+/// returned instead.
+pub fn plan_geodes(
+    configuration: &Configuration,
+    held: [u16; 6usize],
+    wanted: &[String],
+) -> Result<GeodePlan> {
+    if wanted.len() > MAX_WANTED {
+        bail!("At most {} wanted items can be planned for.", MAX_WANTED);
+    }
+
+    let total: i32 = held.iter().map(|held: &u16| *held as i32).sum();
+    if total > MAX_GEODES {
+        bail!("At most {} geodes can be planned for.", MAX_GEODES);
+    }
+
+    let geodes_cracked: i32 = configuration.geodes_cracked.unwrap_or(0u16) as i32;
+    let is_wanted = |geode: &Geode| -> Option<usize> {
+        wanted
+            .iter()
+            .position(|name: &String| geode.item.name_matches(geode.id, name))
+    };
+
+    let mut cracks: Vec<[Geode; 6usize]> = Vec::new();
+    let mut candidates: Vec<Vec<Candidate>> = vec![Vec::new(); wanted.len()];
+    for crack in 0usize..total as usize {
+        let geodes: [Geode; 6usize] = get_geodes(configuration, geodes_cracked + crack as i32)?;
+        for (geode_type, geode) in geodes.iter().enumerate() {
+            if held[geode_type] == 0u16 {
+                continue;
+            }
+
+            if let Some(index) = is_wanted(geode) {
+                candidates[index].push(Candidate { crack, geode_type });
+            }
+        }
+        cracks.push(geodes);
+    }
+    candidates.retain(|candidates: &Vec<Candidate>| !candidates.is_empty());
+    candidates.sort_by_key(|candidates: &Vec<Candidate>| candidates.len());
+
+    let mut search: Search = Search {
+        held,
+        candidates: &candidates,
+        cracks_used: vec![false; total as usize],
+        geodes_used: [0u16; 6usize],
+        chosen: Vec::new(),
+        best: Vec::new(),
+        steps: 0u32,
+    };
+    let optimal: bool = search.search(0usize);
+
+    let mut assigned: Vec<Option<usize>> = vec![None; total as usize];
+    let mut left: [u16; 6usize] = held;
+    for candidate in search.best.iter() {
+        assigned[candidate.crack] = Some(candidate.geode_type);
+        left[candidate.geode_type] -= 1u16;
+    }
+
+    let mut plan: Vec<PlannedCrack> = Vec::new();
+    for (crack, assigned) in assigned.into_iter().enumerate() {
+        let geode_type: usize = match assigned {
+            Some(geode_type) => geode_type,
+            None => {
+                // The counts add up to the cracks left, so there is always one.
+                let geode_type: usize = left
+                    .iter()
+                    .position(|left: &u16| *left > 0u16)
+                    .unwrap_or(0usize);
+                left[geode_type] -= 1u16;
+                geode_type
+            }
+        };
+
+        let geode: Geode = cracks[crack][geode_type];
+        plan.push(PlannedCrack {
+            geodes_cracked: geodes_cracked + crack as i32,
+            geode_type: GEODE_TYPES[geode_type],
+            geode,
+            wanted: is_wanted(&geode).is_some(),
+        });
+    }
+
+    Ok(GeodePlan {
+        cracks: plan,
+        optimal,
+    })
+}
@@ -0,0 +1,296 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use std::ops::Range;
+
+use anyhow::{bail, Context, Error, Result};
+
+use crate::codegen::{ObjectInformation, ObjectInformationExtra, HATS, OBJECT_INFORMATION};
+use crate::configuration::Configuration;
+use crate::predictors::util::{get_prng, Item};
+use crate::prng::Prng;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum GeodeType {
+    Geode,
+    FrozenGeode,
+    MagmaGeode,
+    OmniGeode,
+    ArtifactTrove,
+    GoldenCoconut,
+}
+
+pub const GEODE_TYPES: [GeodeType; 6usize] = [
+    GeodeType::Geode,
+    GeodeType::FrozenGeode,
+    GeodeType::MagmaGeode,
+    GeodeType::OmniGeode,
+    GeodeType::ArtifactTrove,
+    GeodeType::GoldenCoconut,
+];
+
+impl Display for GeodeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Geode => "Geode",
+                Self::FrozenGeode => "Frozen Geode",
+                Self::MagmaGeode => "Magma Geode",
+                Self::OmniGeode => "Omni Geode",
+                Self::ArtifactTrove => "Artifact Trove",
+                Self::GoldenCoconut => "Golden Coconut",
+            }
+        )
+    }
+}
+
+impl FromStr for GeodeType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s.to_lowercase().as_str() {
+            "geode" => Self::Geode,
+            "frozen" | "frozen geode" => Self::FrozenGeode,
+            "magma" | "magma geode" => Self::MagmaGeode,
+            "omni" | "omni geode" => Self::OmniGeode,
+            "trove" | "artifact trove" => Self::ArtifactTrove,
+            "coconut" | "golden coconut" => Self::GoldenCoconut,
+            _ => bail!("Unknown geode type {}.", s),
+        })
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Geode {
+    pub id: u16,
+    pub item: Item,
+    pub quantity: u8,
+}
+
+pub fn get_geode(
+    configuration: &Configuration,
+    geode_type: GeodeType,
+    geodes_cracked: i32,
+) -> Result<Geode> {
+    let mut prng: Box<dyn Prng> = get_prng(
+        configuration.platform,
+        (configuration.seed / 2i32).wrapping_add(geodes_cracked),
+    )?;
+
+    generate_geode(&mut *prng, configuration, geode_type, geodes_cracked)
+}
+
+/// Generates the geode using a PRNG seeded with `seed / 2 + geodes_cracked`.
+pub fn generate_geode<P: Prng + ?Sized>(
+    prng: &mut P,
+    configuration: &Configuration,
+    geode_type: GeodeType,
+    geodes_cracked: i32,
+) -> Result<Geode> {
+    for _ in 0i32..prng.gen_range(1i32..10i32)? {
+        prng.gen_float()?;
+    }
+    for _ in 0i32..prng.gen_range(1i32..10i32)? {
+        prng.gen_float()?;
+    }
+
+    if prng.gen_float()? <= 0.1f64 && configuration.qis_crop.unwrap_or(false) {
+        return Ok(Geode {
+            id: 890u16,
+            item: Item::ObjectInformation(
+                OBJECT_INFORMATION
+                    .get(&890u16)
+                    .context("Error getting object information.")?,
+            ),
+            quantity: if prng.gen_float()? < 0.25f64 {
+                5u8
+            } else {
+                1u8
+            },
+        });
+    }
+
+    if geode_type == GeodeType::GoldenCoconut {
+        if prng.gen_float()? < 0.05f64 && !configuration.golden_helmet.unwrap_or(true) {
+            return Ok(Geode {
+                id: 75u16,
+                item: Item::Hats(HATS.get(&75u16).context("Error getting hats.")?),
+                quantity: 1u8,
+            });
+        }
+
+        let (id, quantity): (u16, u8) = match prng.gen_range(0i32..7i32)? {
+            0i32 => (69u16, 1u8),
+            1i32 => (835u16, 1u8),
+            2i32 => (833u16, 5u8),
+            3i32 => (831u16, 5u8),
+            4i32 => (820u16, 1u8),
+            5i32 => (292u16, 1u8),
+            6i32 => (386u16, 5u8),
+            _ => panic!(),
+        };
+
+        return Ok(Geode {
+            id,
+            item: Item::ObjectInformation(
+                OBJECT_INFORMATION
+                    .get(&id)
+                    .context("Error getting object information.")?,
+            ),
+            quantity,
+        });
+    }
+
+    if geode_type == GeodeType::ArtifactTrove || prng.gen_float()? >= 0.5f64 {
+        // Artefact trove
+        let object_information: &ObjectInformation = OBJECT_INFORMATION
+            .get(&match geode_type {
+                GeodeType::Geode => 535u16,
+                GeodeType::FrozenGeode => 536u16,
+                GeodeType::MagmaGeode => 537u16,
+                GeodeType::OmniGeode => 749u16,
+                GeodeType::ArtifactTrove => 275u16,
+                GeodeType::GoldenCoconut => panic!(),
+            })
+            .context("Error getting object information.")?;
+
+        let treasure: &[u16] = match object_information.extra {
+            ObjectInformationExtra::Treasure(treasure) => treasure,
+            _ => panic!(),
+        };
+
+        let mut id: u16 = treasure[prng.gen_range(0i32..treasure.len() as i32)? as usize];
+
+        if geode_type == GeodeType::OmniGeode
+            && prng.gen_float()? < 0.008f64
+            && geodes_cracked > 15i32
+        {
+            // Omni geode
+            id = 74u16;
+        }
+
+        return Ok(Geode {
+            id,
+            item: Item::ObjectInformation(
+                OBJECT_INFORMATION
+                    .get(&id)
+                    .context("Error getting object information.")?,
+            ),
+            quantity: 1u8,
+        });
+    }
+
+    let mut quantity: u8 = (prng.gen_range(0i32..3i32)? as u8) * 2u8 + 1u8;
+    if prng.gen_float()? < 0.1f64 {
+        quantity = 10u8;
+    }
+    if prng.gen_float()? < 0.01f64 {
+        quantity = 20u8;
+    }
+
+    let (id, quantity): (u16, u8) = if prng.gen_float()? < 0.5f64 {
+        match prng.gen_range(0i32..4i32)? {
+            0i32 | 1i32 => (390u16, quantity),
+            2i32 => (330u16, 1u8),
+            3i32 => match geode_type {
+                GeodeType::Geode => (86u16, 1u8),
+                GeodeType::FrozenGeode => (84u16, 1u8),
+                GeodeType::MagmaGeode => (82u16, 1u8),
+                GeodeType::OmniGeode => (82u16 + (prng.gen_range(0i32..3i32)? as u16) * 2u16, 1u8),
+                _ => panic!(),
+            },
+            _ => panic!(),
+        }
+    } else {
+        match geode_type {
+            GeodeType::Geode => match prng.gen_range(0i32..3i32)? {
+                0i32 => (378u16, quantity),
+                1i32 => (
+                    if configuration.mine_level.unwrap_or(120u8) > 25u8 {
+                        380u16
+                    } else {
+                        378u16
+                    },
+                    quantity,
+                ),
+                2i32 => (382u16, quantity),
+                _ => panic!(),
+            },
+            GeodeType::FrozenGeode => match prng.gen_range(0i32..4i32)? {
+                0i32 => (378u16, quantity),
+                1i32 => (380u16, quantity),
+                2i32 => (382u16, quantity),
+                3i32 => (
+                    if configuration.mine_level.unwrap_or(120u8) > 75u8 {
+                        384u16
+                    } else {
+                        380u16
+                    },
+                    quantity,
+                ),
+                _ => panic!(),
+            },
+            GeodeType::MagmaGeode | GeodeType::OmniGeode => match prng.gen_range(0i32..5i32)? {
+                0i32 => (378u16, quantity),
+                1i32 => (380u16, quantity),
+                2i32 => (382u16, quantity),
+                3i32 => (384u16, quantity),
+                4i32 => (386u16, quantity / 2u8 + 1u8),
+                _ => panic!(),
+            },
+            _ => panic!(),
+        }
+    };
+
+    Ok(Geode {
+        id,
+        item: Item::ObjectInformation(
+            OBJECT_INFORMATION
+                .get(&id)
+                .context("Error getting object information.")?,
+        ),
+        quantity,
+    })
+}
+
+/// The result of cracking each geode type next, in `GEODE_TYPES` order.
+pub fn get_geodes(configuration: &Configuration, geodes_cracked: i32) -> Result<[Geode; 6usize]> {
+    Ok([
+        get_geode(configuration, GeodeType::Geode, geodes_cracked)?,
+        get_geode(configuration, GeodeType::FrozenGeode, geodes_cracked)?,
+        get_geode(configuration, GeodeType::MagmaGeode, geodes_cracked)?,
+        get_geode(configuration, GeodeType::OmniGeode, geodes_cracked)?,
+        get_geode(configuration, GeodeType::ArtifactTrove, geodes_cracked)?,
+        get_geode(configuration, GeodeType::GoldenCoconut, geodes_cracked)?,
+    ])
+}
+
+/// The first `count` geodes whose lowercase item name contains `filter`, ordered by geodes
+/// cracked and then by type.
+pub fn find_geodes(
+    configuration: &Configuration,
+    geodes_cracked: Range<i32>,
+    filter: &str,
+    count: usize,
+) -> Result<Vec<(i32, GeodeType, Geode)>> {
+    let mut found: Vec<(i32, GeodeType, Geode)> = Vec::new();
+    for geodes_cracked in geodes_cracked {
+        if found.len() >= count {
+            break;
+        }
+
+        for (geode_type, geode) in GEODE_TYPES
+            .into_iter()
+            .zip(get_geodes(configuration, geodes_cracked)?)
+        {
+            if geode.item.name(geode.id).to_lowercase().contains(filter) {
+                found.push((geodes_cracked, geode_type, geode));
+            }
+        }
+    }
+    found.truncate(count);
+
+    Ok(found)
+}
@@ -0,0 +1,39 @@
+use anyhow::Result;
+
+use crate::configuration::Configuration;
+use crate::predictors::util::{get_prng, Item, StockItem, StockPredictor};
+use crate::prng::Prng;
+
+pub struct JojaPredictor {}
+
+impl StockPredictor for JojaPredictor {
+    fn get_stock(configuration: &Configuration, date: i32) -> Result<Option<Vec<StockItem>>> {
+        let mut prng: Box<dyn Prng> = get_prng(
+            configuration.platform,
+            (configuration.seed / 2i32)
+                .wrapping_add(date)
+                .wrapping_add(1i32),
+        )?;
+
+        Ok(Some(vec![
+            {
+                let mut id: u16 = prng.gen_range(0i32..112i32)? as u16;
+                if id == 21 {
+                    id = 22u16;
+                }
+                StockItem {
+                    id,
+                    item: Item::Wallpaper(false),
+                    price: 250u32,
+                    quantity: 1u8,
+                }
+            },
+            StockItem {
+                id: prng.gen_range(0i32..40i32)? as u16,
+                item: Item::Wallpaper(true),
+                price: 250u32,
+                quantity: 1u8,
+            },
+        ]))
+    }
+}
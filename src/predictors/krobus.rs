@@ -0,0 +1,56 @@
+use anyhow::{Context, Result};
+
+use crate::codegen::OBJECT_INFORMATION;
+use crate::configuration::Configuration;
+use crate::predictors::util::Item::ObjectInformation;
+use crate::predictors::util::{day_number, get_prng, StockItem, StockPredictor};
+use crate::prng::Prng;
+
+pub struct KrobusPredictor {}
+
+impl StockPredictor for KrobusPredictor {
+    fn get_stock(configuration: &Configuration, date: i32) -> Result<Option<Vec<StockItem>>> {
+        match day_number(date) % 7u8 {
+            2u8 | 5u8 => {}
+            _ => return Ok(None),
+        }
+
+        let mut prng: Box<dyn Prng> = get_prng(
+            configuration.platform,
+            (configuration.seed / 2i32).wrapping_add(date),
+        )?;
+
+        Ok(Some(vec![match day_number(date) % 7u8 {
+            2u8 => {
+                let id = prng.gen_range(698i32..709i32)? as u16;
+                StockItem {
+                    id,
+                    item: ObjectInformation(
+                        OBJECT_INFORMATION
+                            .get(&id)
+                            .context("Error getting object information.")?,
+                    ),
+                    price: 200u32,
+                    quantity: 5u8,
+                }
+            }
+            5u8 => {
+                let mut id = prng.gen_range(194i32..245i32)? as u16;
+                if id == 217u16 {
+                    id = 216u16;
+                }
+                StockItem {
+                    id,
+                    item: ObjectInformation(
+                        OBJECT_INFORMATION
+                            .get(&id)
+                            .context("Error getting object information.")?,
+                    ),
+                    price: prng.gen_range(5i32..51i32)? as u32 * 10u32,
+                    quantity: 5u8,
+                }
+            }
+            _ => panic!(),
+        }]))
+    }
+}
@@ -0,0 +1,64 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+
+use crate::configuration::Configuration;
+use crate::predictors::saloon::generate_dish_of_the_day;
+use crate::predictors::util::get_overnight_prng;
+use crate::prng::Prng;
+
+/// The fortune teller's reading of the daily luck, from `TV.getFortuneForecast`.
+#[derive(Clone, Copy, PartialEq)]
+pub enum SpiritsMood {
+    VeryHappy,
+    GoodHumor,
+    Neutral,
+    CompletelyNeutral,
+    SomewhatAnnoyed,
+    VeryDispleased,
+}
+
+impl SpiritsMood {
+    pub fn from_luck(luck: f64) -> Self {
+        if luck > 0.07f64 {
+            SpiritsMood::VeryHappy
+        } else if luck > 0.02f64 {
+            SpiritsMood::GoodHumor
+        } else if luck < -0.07f64 {
+            SpiritsMood::VeryDispleased
+        } else if luck < -0.02f64 {
+            SpiritsMood::SomewhatAnnoyed
+        } else if luck == 0f64 {
+            SpiritsMood::CompletelyNeutral
+        } else {
+            SpiritsMood::Neutral
+        }
+    }
+}
+
+impl Display for SpiritsMood {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                SpiritsMood::VeryHappy => "Very happy",
+                SpiritsMood::GoodHumor => "Good humor",
+                SpiritsMood::Neutral => "Neutral",
+                SpiritsMood::CompletelyNeutral => "Completely neutral",
+                SpiritsMood::SomewhatAnnoyed => "Somewhat annoyed",
+                SpiritsMood::VeryDispleased => "Very displeased",
+            }
+        )
+    }
+}
+
+/// The daily luck on `date`, which is rolled overnight (`Game1._newDayAfterFade`) using the total
+/// steps taken when going to bed, after the dish of the day. It doesn't include the special charm's
+/// 0.025.
+pub fn get_daily_luck(configuration: &Configuration, date: i32, steps_taken: u32) -> Result<f64> {
+    let mut prng: Box<dyn Prng> = get_overnight_prng(configuration, date, steps_taken)?;
+    generate_dish_of_the_day(prng.as_mut())?;
+
+    Ok((prng.gen_range(-100i32..101i32)? as f64 / 1000f64).min(0.1f64))
+}
@@ -0,0 +1,120 @@
+use std::cmp::min;
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+
+use anyhow::{bail, Result};
+
+use crate::configuration::Configuration;
+use crate::predictors::util::get_prng;
+use crate::prng::Prng;
+
+/// The last level of the mines, below which is the Skull Cavern.
+pub const MINES_BOTTOM: u8 = 120u8;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Infestation {
+    Monsters,
+    Slimes,
+}
+
+impl Display for Infestation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Infestation::Monsters => "Monster infested",
+                Infestation::Slimes => "Slime infested",
+            }
+        )
+    }
+}
+
+/// The modifiers of a mine level on a day, from `MineShaft.loadLevel`.
+#[derive(Clone, Copy, PartialEq)]
+pub struct MineFloor {
+    pub level: u8,
+    pub infestation: Option<Infestation>,
+    /// Darker than normal lighting.
+    pub dark: bool,
+    /// Rainbow lights, with mushrooms growing on the floor.
+    pub mushroom: bool,
+}
+
+impl MineFloor {
+    pub fn is_normal(&self) -> bool {
+        self.infestation.is_none() && !self.dark && !self.mushroom
+    }
+}
+
+/// Generates the floor using PRNGs seeded with `seed / 2 + date + level * 100`. The quarry floors
+/// (after the crafts room bundles) aren't included.
+pub fn get_mine_floor(configuration: &Configuration, date: i32, level: u8) -> Result<MineFloor> {
+    if level == 0u8 {
+        bail!("Mine levels start at 1.");
+    }
+    if level > MINES_BOTTOM {
+        bail!(
+            "Only the mines' levels 1 to {} are predicted, not the Skull Cavern.",
+            MINES_BOTTOM
+        );
+    }
+
+    let mut floor: MineFloor = MineFloor {
+        level,
+        infestation: None,
+        dark: false,
+        mushroom: false,
+    };
+
+    // Elevator levels and the bottom always load the same map.
+    if level.is_multiple_of(5u8) {
+        return Ok(floor);
+    }
+
+    let seed: i32 = (configuration.seed / 2i32)
+        .wrapping_add(date)
+        .wrapping_add(level as i32 * 100i32);
+
+    let map_number: u8 = level % 40u8;
+    if map_number > 5u8 && map_number < 30u8 && map_number != 19u8 {
+        let mut prng: Box<dyn Prng> = get_prng(configuration.platform, seed)?;
+        if prng.gen_float()? < 0.044f64 {
+            floor.infestation = Some(if prng.gen_float()? < 0.5f64 {
+                Infestation::Monsters
+            } else {
+                Infestation::Slimes
+            });
+
+            // The infestation takes over the rest of the level.
+            return Ok(floor);
+        }
+    }
+
+    // The lighting uses a new PRNG with the same seed.
+    let mut prng: Box<dyn Prng> = get_prng(configuration.platform, seed)?;
+    if prng.gen_float()? < 0.3f64 && level > 2u8 {
+        floor.dark = true;
+        // The darkness.
+        prng.gen_float()?;
+    }
+    prng.gen_float()?;
+    floor.mushroom = prng.gen_float()? < 0.035f64 && level > 80u8;
+
+    Ok(floor)
+}
+
+pub fn get_mine_floors(
+    configuration: &Configuration,
+    date: i32,
+    levels: RangeInclusive<u8>,
+) -> Result<Vec<MineFloor>> {
+    levels
+        .map(|level: u8| get_mine_floor(configuration, date, level))
+        .collect()
+}
+
+/// The deepest elevator stop that doesn't go past `level`, given the deepest level reached.
+pub fn get_elevator_level(configuration: &Configuration, level: u8) -> u8 {
+    min(level, configuration.mine_level.unwrap_or(MINES_BOTTOM)) / 5u8 * 5u8
+}
@@ -0,0 +1,30 @@
+use crate::codegen::{ObjectInformation, OBJECT_INFORMATION};
+use crate::predictors::util::Item;
+
+/// Mirrors `LibraryMuseum.isItemSuitableForDonation`.
+pub fn is_museum_item(object_information: &ObjectInformation) -> bool {
+    object_information.type_and_category.starts_with("Arch")
+        || object_information.type_and_category.starts_with("Minerals")
+}
+
+/// The IDs of every mineral and artifact that can be donated, in order.
+pub fn get_museum_items() -> Vec<u16> {
+    let mut ids: Vec<u16> = OBJECT_INFORMATION
+        .entries()
+        .filter(|(_, object_information)| is_museum_item(object_information))
+        .map(|(id, _)| *id)
+        .collect();
+    ids.sort();
+
+    ids
+}
+
+/// Whether the item can be donated and isn't in `donated` yet.
+pub fn is_still_needed(item: &Item, id: u16, donated: &[u16]) -> bool {
+    match item {
+        Item::ObjectInformation(object_information) => {
+            is_museum_item(object_information) && !donated.contains(&id)
+        }
+        _ => false,
+    }
+}
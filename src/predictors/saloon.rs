@@ -0,0 +1,53 @@
+use anyhow::{Context, Result};
+
+use crate::codegen::OBJECT_INFORMATION;
+use crate::configuration::Configuration;
+use crate::predictors::util::Item::ObjectInformation;
+use crate::predictors::util::{get_overnight_prng, StockItem, StockPredictor};
+use crate::prng::Prng;
+
+// Dishes Gus always sells, which the dish of the day is re-rolled until it isn't, from
+// `Utility.getForbiddenDishesOfTheDay`.
+const FORBIDDEN_DISHES: [u16; 7usize] = [346u16, 196u16, 216u16, 224u16, 206u16, 395u16, 217u16];
+
+/// Rolls the dish of the day and its stack, which are the first rolls after the day of the month
+/// ones overnight.
+pub fn generate_dish_of_the_day<P: Prng + ?Sized>(prng: &mut P) -> Result<(u16, u8)> {
+    let mut id: u16 = prng.gen_range(194i32..240i32)? as u16;
+    while FORBIDDEN_DISHES.contains(&id) {
+        id = prng.gen_range(194i32..240i32)? as u16;
+    }
+    let extra: i32 = if prng.gen_float()? < 0.08f64 {
+        10i32
+    } else {
+        0i32
+    };
+    let quantity: u8 = prng.gen_range(1i32..4i32 + extra)? as u8;
+
+    Ok((id, quantity))
+}
+
+/// Gus's dish of the day, which is rolled overnight using the total steps taken when going to bed.
+/// The steps taken optional parameter is used for every date.
+pub struct SaloonPredictor {}
+
+impl StockPredictor for SaloonPredictor {
+    fn get_stock(configuration: &Configuration, date: i32) -> Result<Option<Vec<StockItem>>> {
+        let mut prng: Box<dyn Prng> = get_overnight_prng(
+            configuration,
+            date,
+            configuration.steps_taken.unwrap_or(0u32),
+        )?;
+        let (id, quantity): (u16, u8) = generate_dish_of_the_day(prng.as_mut())?;
+
+        let object_information = OBJECT_INFORMATION
+            .get(&id)
+            .context("Error getting object information.")?;
+        Ok(Some(vec![StockItem {
+            id,
+            item: ObjectInformation(object_information),
+            price: object_information.price * 3u32,
+            quantity,
+        }]))
+    }
+}
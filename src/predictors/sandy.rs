@@ -0,0 +1,41 @@
+use anyhow::{Context, Result};
+
+use crate::codegen::FURNITURE;
+use crate::configuration::Configuration;
+use crate::predictors::util::{
+    day_number, get_clothing_information, get_prng, Item, StockItem, StockPredictor,
+};
+use crate::prng::Prng;
+
+pub struct SandyPredictor {}
+
+impl StockPredictor for SandyPredictor {
+    fn get_stock(configuration: &Configuration, date: i32) -> Result<Option<Vec<StockItem>>> {
+        let mut prng: Box<dyn Prng> = get_prng(
+            configuration.platform,
+            (configuration.seed / 2i32).wrapping_add(date),
+        )?;
+
+        let mut stock_items: Vec<StockItem> = vec![{
+            let id: u16 = 1000u16 + prng.gen_range(0i32..127i32)? as u16;
+            StockItem {
+                id,
+                item: Item::ClothingInformation(get_clothing_information(id)?),
+                price: 700u32,
+                quantity: 1u8,
+            }
+        }];
+
+        if day_number(date) % 7u8 == 1u8 {
+            let id: u16 = 2734u16 + prng.gen_range(0i32..4i32)? as u16 * 2u16;
+            stock_items.push(StockItem {
+                id,
+                item: Item::Furniture(FURNITURE.get(&id).context("Error getting furniture.")?),
+                price: 500u32,
+                quantity: 1u8,
+            });
+        }
+
+        Ok(Some(stock_items))
+    }
+}
@@ -0,0 +1,248 @@
+use std::fmt::Display;
+
+use anyhow::{Context, Result};
+
+use crate::codegen::{HATS, OBJECT_INFORMATION};
+use crate::configuration::Configuration;
+use crate::predictors::saloon::generate_dish_of_the_day;
+use crate::predictors::util::{get_overnight_prng, get_prng, Item};
+use crate::prng::Prng;
+
+/// The highest daily luck, with the special charm.
+const MAX_LUCK: f64 = 0.125f64;
+/// The lowest daily luck.
+const MIN_LUCK: f64 = -0.1f64;
+
+/// The town's trash cans, in the order of their `Garbage` action number.
+#[derive(Clone, Copy, PartialEq)]
+pub enum TrashCan {
+    JodiAndKent,
+    EmilyAndHaley,
+    Lewis,
+    Museum,
+    Blacksmith,
+    Saloon,
+    EvelynAndGeorge,
+    JojaMart,
+}
+
+pub const TRASH_CANS: [TrashCan; 8usize] = [
+    TrashCan::JodiAndKent,
+    TrashCan::EmilyAndHaley,
+    TrashCan::Lewis,
+    TrashCan::Museum,
+    TrashCan::Blacksmith,
+    TrashCan::Saloon,
+    TrashCan::EvelynAndGeorge,
+    TrashCan::JojaMart,
+];
+
+impl Display for TrashCan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                TrashCan::JodiAndKent => "Jodi and Kent",
+                TrashCan::EmilyAndHaley => "Emily and Haley",
+                TrashCan::Lewis => "Lewis",
+                TrashCan::Museum => "Museum",
+                TrashCan::Blacksmith => "Blacksmith",
+                TrashCan::Saloon => "Saloon",
+                TrashCan::EvelynAndGeorge => "Evelyn and George",
+                TrashCan::JojaMart => "JojaMart",
+            }
+        )
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum TrashItem {
+    Item {
+        id: u16,
+        item: Item,
+    },
+    /// From `Utility.getRandomItemFromSeason`, which depends on the areas unlocked.
+    Seasonal,
+}
+
+impl TrashItem {
+    pub fn name(&self) -> String {
+        match self {
+            TrashItem::Item { id, item } => item.name(*id),
+            TrashItem::Seasonal => "Seasonal item".to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct TrashFind {
+    pub trash_item: TrashItem,
+    /// The daily luck has to be above this, including the special charm's 0.025. `None` when any
+    /// luck does.
+    pub luck: Option<f64>,
+}
+
+/// What a trash can gives, if anything. `special` replaces `find` with enough luck.
+#[derive(Clone, Copy)]
+pub struct TrashCanLoot {
+    pub trash_can: TrashCan,
+    pub find: Option<TrashFind>,
+    pub special: Option<TrashFind>,
+}
+
+fn object(id: u16) -> Result<TrashItem> {
+    Ok(TrashItem::Item {
+        id,
+        item: Item::ObjectInformation(
+            OBJECT_INFORMATION
+                .get(&id)
+                .context("Error getting object information.")?,
+        ),
+    })
+}
+
+/// The luck needed for a `NextDouble() < 0.2 + luck` roll, or `None` inside the option when any
+/// luck does. Returns `None` when no luck is enough.
+fn luck_needed(roll: f64, luck: Option<f64>) -> Option<Option<f64>> {
+    let needed: f64 = roll - 0.2f64;
+    if needed >= MAX_LUCK {
+        None
+    } else if needed < MIN_LUCK {
+        Some(luck)
+    } else {
+        Some(Some(luck.map_or(needed, |luck: f64| luck.max(needed))))
+    }
+}
+
+/// Mirrors the garbage action in `Town.checkAction`, using a PRNG seeded with
+/// `seed / 2 + date + 777 + trash can * 77`.
+pub fn get_trash_can_loot(
+    configuration: &Configuration,
+    date: i32,
+    trash_can: TrashCan,
+) -> Result<TrashCanLoot> {
+    let which_can: i32 = trash_can as i32;
+    let mut prng: Box<dyn Prng> = get_prng(
+        configuration.platform,
+        (configuration.seed / 2i32)
+            .wrapping_add(date)
+            .wrapping_add(777i32 + which_can * 77i32),
+    )?;
+
+    for _ in 0u8..2u8 {
+        let prewarm: i32 = prng.gen_range(0i32..100i32)?;
+        for _ in 0i32..prewarm {
+            prng.gen_float()?;
+        }
+    }
+
+    let mut loot: TrashCanLoot = TrashCanLoot {
+        trash_can,
+        find: None,
+        special: None,
+    };
+
+    // The stat is incremented first, so this needs 20 trash cans checked before.
+    let experienced: bool = configuration.trash_cans_checked.unwrap_or(20u32) >= 20u32;
+    let mega: bool = experienced && prng.gen_float()? < 0.01f64;
+    let double_mega: bool = experienced && prng.gen_float()? < 0.002f64;
+    if double_mega {
+        loot.find = Some(TrashFind {
+            trash_item: TrashItem::Item {
+                id: 66u16,
+                item: Item::Hats(HATS.get(&66u16).context("Error getting hats.")?),
+            },
+            luck: None,
+        });
+        return Ok(loot);
+    }
+
+    let luck: Option<f64> = if mega {
+        None
+    } else {
+        match luck_needed(prng.gen_float()?, None) {
+            Some(luck) => luck,
+            None => return Ok(loot),
+        }
+    };
+
+    let trash_item: TrashItem = match prng.gen_range(0i32..10i32)? {
+        0i32 => object(168u16)?,
+        1i32 => object(167u16)?,
+        2i32 => object(170u16)?,
+        3i32 => object(171u16)?,
+        4i32 => object(172u16)?,
+        5i32 => object(216u16)?,
+        6i32 => TrashItem::Seasonal,
+        7i32 => object(403u16)?,
+        8i32 => object(309u16 + prng.gen_range(0i32..3i32)? as u16)?,
+        _ => object(153u16)?,
+    };
+    loot.find = Some(TrashFind { trash_item, luck });
+
+    loot.special = match trash_can {
+        TrashCan::Museum => match luck_needed(prng.gen_float()?, luck) {
+            Some(luck) => Some(TrashFind {
+                trash_item: object(if prng.gen_float()? < 0.05f64 {
+                    749u16
+                } else {
+                    535u16
+                })?,
+                luck,
+            }),
+            None => None,
+        },
+        TrashCan::Blacksmith => match luck_needed(prng.gen_float()?, luck) {
+            Some(luck) => Some(TrashFind {
+                trash_item: object(378u16 + prng.gen_range(0i32..3i32)? as u16 * 2u16)?,
+                luck,
+            }),
+            None => None,
+        },
+        TrashCan::Saloon => match luck_needed(prng.gen_float()?, luck) {
+            Some(luck) => {
+                // The dish of the day rolled the night before.
+                let mut overnight_prng: Box<dyn Prng> = get_overnight_prng(
+                    configuration,
+                    date,
+                    configuration.steps_taken.unwrap_or(0u32),
+                )?;
+                let (id, _): (u16, u8) = generate_dish_of_the_day(overnight_prng.as_mut())?;
+                Some(TrashFind {
+                    trash_item: object(if id == 217u16 { 216u16 } else { id })?,
+                    luck,
+                })
+            }
+            None => None,
+        },
+        TrashCan::EvelynAndGeorge => match luck_needed(prng.gen_float()?, luck) {
+            Some(luck) => Some(TrashFind {
+                trash_item: object(223u16)?,
+                luck,
+            }),
+            None => None,
+        },
+        // Without luck, and only before the community center is restored.
+        TrashCan::JojaMart => {
+            if prng.gen_float()? < 0.2f64 {
+                Some(TrashFind {
+                    trash_item: object(167u16)?,
+                    luck,
+                })
+            } else {
+                None
+            }
+        }
+        _ => None,
+    };
+
+    Ok(loot)
+}
+
+pub fn get_trash_cans_loot(configuration: &Configuration, date: i32) -> Result<Vec<TrashCanLoot>> {
+    TRASH_CANS
+        .into_iter()
+        .map(|trash_can: TrashCan| get_trash_can_loot(configuration, date, trash_can))
+        .collect()
+}
@@ -0,0 +1,280 @@
+use std::cmp::max;
+
+use anyhow::{Context, Result};
+
+use crate::codegen::{
+    ObjectInformation, BIG_CRAFTABLES_INFORMATION, FURNITURE, OBJECT_INFORMATION,
+    OBJECT_INFORMATION_OFF_LIMIT,
+};
+use crate::configuration::{Configuration, Platform};
+use crate::predictors::util::{
+    day_number, get_prng, get_random_furniture, season_number, Item, StockItem, StockPredictor,
+};
+use crate::prng::Prng;
+
+macro_rules! second_check {
+    ($object_information:ident) => {
+        if !$object_information.type_and_category.contains("-")
+            || $object_information.price <= 0
+            || $object_information.type_and_category.contains("-13")
+            || $object_information.type_and_category == "Quest"
+            || $object_information.name == "Weeds"
+            || $object_information.type_and_category.contains("Minerals")
+            || $object_information.type_and_category.contains("Arch")
+        {
+            continue;
+        }
+    };
+}
+
+macro_rules! second_rng {
+    ($prng:ident, $constant_multiplier:ident, $variable_multiplier:ident, $quantity_decider:ident) => {
+        $constant_multiplier = $prng.gen_range(1i32..11i32)? as u32;
+        $variable_multiplier = $prng.gen_range(3i32..6i32)? as u32;
+        $quantity_decider = $prng.gen_float()?;
+    };
+}
+
+macro_rules! gen_furniture_id {
+    ($prng:ident, $furniture_id:ident) => {
+        $furniture_id = get_random_furniture($prng, 0u16, 1613u16)?;
+    };
+}
+
+macro_rules! gen_furniture_price {
+    ($prng:ident, $furniture_price:ident) => {
+        $furniture_price = $prng.gen_range(1i32..11i32)? as u32 * 250u32;
+    };
+}
+
+pub fn is_open(date: i32) -> bool {
+    matches!(
+        (
+            day_number(date) % 7u8,
+            day_number(date),
+            season_number(date),
+        ),
+        // Dates start at 1, hence the subtraction.
+        (4u8 | 6u8, _, _) // Friday or sunday.
+            | (_, 14u8..=16u8, 3u8) // Night market.
+    )
+}
+
+/// `VisitsUntilY1Guarantee` at the start of a game with the "guarantee year 1 completable" option.
+const VISITS_UNTIL_Y1_GUARANTEE: i32 = 2i32;
+
+/// Red Cabbage Seeds, the bundle item that can't otherwise be bought in year 1.
+const Y1_GUARANTEED_ITEM: u16 = 485u16;
+
+/// Visits to the forest up to and including `date`, each of which decrements
+/// `VisitsUntilY1Guarantee` in `Forest.DayUpdate`. Night market days don't.
+fn forest_visits(date: i32) -> i32 {
+    2i32 * (date / 7i32) + i32::from(date % 7i32 >= 5i32)
+}
+
+/// Whether the stock includes the year 1 completable guarantee, which is added while
+/// `VisitsUntilY1Guarantee` is 0, so from the visit that decrements it to 0 until the next one.
+pub fn has_y1_guarantee(configuration: &Configuration, date: i32) -> bool {
+    let (visits_until_y1_guarantee, as_of): (i32, i32) = match (
+        configuration.visits_until_y1_guarantee,
+        configuration.year_one_completable,
+    ) {
+        (Some(visits_until_y1_guarantee), _) => (
+            visits_until_y1_guarantee,
+            configuration.date.unwrap_or(1i32),
+        ),
+        (None, Some(true)) => (VISITS_UNTIL_Y1_GUARANTEE, 0i32),
+        _ => return false,
+    };
+
+    visits_until_y1_guarantee >= 0i32
+        && forest_visits(date) - forest_visits(as_of) == visits_until_y1_guarantee
+}
+
+/// Generates the stock into `stock_items`, which is cleared first so it can be reused between days.
+pub fn generate_stock<P: Prng + ?Sized>(
+    prng: &mut P,
+    platform: Platform,
+    date: i32,
+    y1_guarantee: bool,
+    stock_items: &mut Vec<StockItem>,
+) -> Result<()> {
+    stock_items.clear();
+
+    for _ in 0u8..10u8 {
+        let mut id: u16 = prng.gen_range(2i32..790i32)? as u16;
+        stock_items.push(loop {
+            id += 1u16;
+            id %= 790u16;
+
+            if !OBJECT_INFORMATION.contains_key(&id) || OBJECT_INFORMATION_OFF_LIMIT.contains(&id) {
+                continue;
+            }
+
+            let object_information: &ObjectInformation = OBJECT_INFORMATION
+                .get(&id)
+                .context("Error getting object information.")?;
+
+            // PC does the second check before the second RNG generation, Switch does the reverse.
+            let constant_multiplier: u32;
+            let variable_multiplier: u32;
+            let quantity_decider: f64;
+            match platform {
+                Platform::PC => {
+                    second_check!(object_information);
+                    second_rng!(
+                        prng,
+                        constant_multiplier,
+                        variable_multiplier,
+                        quantity_decider
+                    );
+                }
+                Platform::Switch => {
+                    second_rng!(
+                        prng,
+                        constant_multiplier,
+                        variable_multiplier,
+                        quantity_decider
+                    );
+                    second_check!(object_information);
+                }
+            }
+
+            if stock_items
+                .iter()
+                .any(|stock_item: &StockItem| stock_item.id == id)
+            {
+                continue;
+            }
+
+            break StockItem {
+                id,
+                item: Item::ObjectInformation(object_information),
+                price: max(
+                    100u32 * constant_multiplier,
+                    object_information.price * variable_multiplier,
+                ),
+                quantity: if quantity_decider < 0.1f64 { 5u8 } else { 1u8 },
+            };
+        });
+    }
+
+    // The guaranteed item is only added if it wasn't picked, with the same rolls as the others.
+    if y1_guarantee
+        && !stock_items
+            .iter()
+            .any(|stock_item: &StockItem| stock_item.id == Y1_GUARANTEED_ITEM)
+    {
+        let object_information: &ObjectInformation = OBJECT_INFORMATION
+            .get(&Y1_GUARANTEED_ITEM)
+            .context("Error getting object information.")?;
+
+        let constant_multiplier: u32;
+        let variable_multiplier: u32;
+        let quantity_decider: f64;
+        second_rng!(
+            prng,
+            constant_multiplier,
+            variable_multiplier,
+            quantity_decider
+        );
+
+        stock_items.push(StockItem {
+            id: Y1_GUARANTEED_ITEM,
+            item: Item::ObjectInformation(object_information),
+            price: max(
+                100u32 * constant_multiplier,
+                object_information.price * variable_multiplier,
+            ),
+            quantity: if quantity_decider < 0.1f64 { 5u8 } else { 1u8 },
+        });
+    }
+
+    let furniture_id: u16;
+    let furniture_price: u32;
+    match platform {
+        Platform::PC => {
+            gen_furniture_id!(prng, furniture_id);
+            gen_furniture_price!(prng, furniture_price);
+        }
+        Platform::Switch => {
+            gen_furniture_price!(prng, furniture_price);
+            gen_furniture_id!(prng, furniture_id);
+        }
+    }
+
+    stock_items.push(StockItem {
+        id: furniture_id,
+        item: Item::Furniture(
+            FURNITURE
+                .get(&furniture_id)
+                .context("Error getting furniture.")?,
+        ),
+        price: furniture_price,
+        quantity: 1u8,
+    });
+
+    if season_number(date) < 2 {
+        stock_items.push(StockItem {
+            id: 347u16,
+            item: Item::ObjectInformation(
+                OBJECT_INFORMATION
+                    .get(&347u16)
+                    .context("Error getting object information.")?,
+            ),
+            price: 1000u32,
+            quantity: if prng.gen_float()? < 0.1f64 { 5u8 } else { 1u8 },
+        });
+    } else if prng.gen_float()? < 0.4f64 {
+        stock_items.push(StockItem {
+            id: 136u16,
+            item: Item::BigCraftablesInformation(
+                BIG_CRAFTABLES_INFORMATION
+                    .get(&136u16)
+                    .context("Error getting big craftables information.")?,
+            ),
+            price: 4000u32,
+            quantity: 1u8,
+        });
+    }
+
+    if prng.gen_float()? < 0.25f64 {
+        stock_items.push(StockItem {
+            id: 433u16,
+            item: Item::ObjectInformation(
+                OBJECT_INFORMATION
+                    .get(&433u16)
+                    .context("Error getting object information.")?,
+            ),
+            price: 2500u32,
+            quantity: 1u8,
+        });
+    }
+
+    Ok(())
+}
+
+pub struct TravelingCartPredictor {}
+
+impl StockPredictor for TravelingCartPredictor {
+    fn get_stock(configuration: &Configuration, date: i32) -> Result<Option<Vec<StockItem>>> {
+        if !is_open(date) {
+            return Ok(None);
+        }
+        let mut prng: Box<dyn Prng> = get_prng(
+            configuration.platform,
+            configuration.seed.wrapping_add(date),
+        )?;
+
+        let mut stock_items: Vec<StockItem> = Vec::<StockItem>::new();
+        generate_stock(
+            &mut *prng,
+            configuration.platform,
+            date,
+            has_y1_guarantee(configuration, date),
+            &mut stock_items,
+        )?;
+
+        Ok(Some(stock_items))
+    }
+}
@@ -0,0 +1,421 @@
+use std::ops::Range;
+
+use anyhow::{bail, Context, Result};
+
+use crate::codegen::{
+    BigCraftablesInformation, ClothingInformation, Furniture, Hats, ObjectInformation,
+    CLOTHING_INFORMATION, FURNITURE, FURNITURE_OFF_LIMIT,
+};
+use crate::configuration::{Configuration, Platform};
+use crate::prng::{Jkiss, MsCorLibRandom, Prng};
+
+#[derive(Clone, Copy)]
+pub enum Item {
+    ObjectInformation(&'static ObjectInformation),
+    BigCraftablesInformation(&'static BigCraftablesInformation),
+    Furniture(&'static Furniture),
+    ClothingInformation(&'static ClothingInformation),
+    Wallpaper(bool),
+    Hats(&'static Hats),
+}
+
+impl Item {
+    pub fn name(&self, id: u16) -> String {
+        match self {
+            Self::ObjectInformation(object_information) => object_information.name.to_string(),
+            Self::BigCraftablesInformation(big_craftable_information) => {
+                big_craftable_information.name.to_string()
+            }
+            Self::Furniture(furniture) => furniture.name.to_string(),
+            Self::ClothingInformation(clothing_information) => format!(
+                "{} ({})",
+                clothing_information.name,
+                if id >= 1000u16 { id - 1000u16 } else { id }
+            ),
+            Self::Wallpaper(flooring) => format!(
+                "{} ({})",
+                if *flooring { "Flooring" } else { "Wallpaper" },
+                id,
+            ),
+            Self::Hats(hats) => hats.name.to_string(),
+        }
+    }
+
+    /// Case-insensitive comparison with `name`, which avoids formatting the name where possible.
+    pub fn name_matches(&self, id: u16, name: &str) -> bool {
+        match self {
+            Self::ObjectInformation(object_information) => {
+                object_information.name.eq_ignore_ascii_case(name)
+            }
+            Self::BigCraftablesInformation(big_craftable_information) => {
+                big_craftable_information.name.eq_ignore_ascii_case(name)
+            }
+            Self::Furniture(furniture) => furniture.name.eq_ignore_ascii_case(name),
+            Self::Hats(hats) => hats.name.eq_ignore_ascii_case(name),
+            Self::ClothingInformation(_) | Self::Wallpaper(_) => {
+                self.name(id).eq_ignore_ascii_case(name)
+            }
+        }
+    }
+}
+
+/// Mirrors `Object.getCategoryName` for the categories in `ObjectInformation`.
+fn category_name(category: i32) -> Option<&'static str> {
+    Some(match category {
+        -2i32 | -12i32 => "Mineral",
+        -4i32 => "Fish",
+        -5i32 | -6i32 | -14i32 | -18i32 => "Animal Product",
+        -7i32 => "Cooking",
+        -8i32 => "Crafting",
+        -15i32 | -16i32 => "Resource",
+        -19i32 => "Fertilizer",
+        -20i32 => "Trash",
+        -21i32 => "Bait",
+        -22i32 => "Fishing Tackle",
+        -24i32 => "Decor",
+        -25i32 => "Cooking",
+        -26i32 | -27i32 => "Artisan Goods",
+        -28i32 => "Monster Loot",
+        -74i32 => "Seed",
+        -75i32 => "Vegetable",
+        -79i32 => "Fruit",
+        -80i32 => "Flower",
+        -81i32 => "Forage",
+        -95i32 => "Hat",
+        -96i32 => "Ring",
+        -97i32 => "Boots",
+        -98i32 => "Weapon",
+        -99i32 => "Tool",
+        _ => return None,
+    })
+}
+
+/// What the game shows about an item, as far as its data records it.
+#[derive(Clone, PartialEq)]
+pub struct ItemDetails {
+    pub display_name: String,
+    pub description: Option<String>,
+    pub category: String,
+    /// The price in the item's data, which is what objects sell for at normal quality.
+    pub price: Option<u32>,
+    /// `None` if inedible.
+    pub edibility: Option<i16>,
+}
+
+impl ItemDetails {
+    /// Mirrors `Object.staminaRecoveredOnConsumption` at normal quality.
+    pub fn energy(&self) -> Option<i32> {
+        self.edibility
+            .map(|edibility: i16| (edibility as f64 * 2.5f64).ceil() as i32)
+    }
+
+    /// Mirrors `Object.healthRecoveredOnConsumption` at normal quality.
+    pub fn health(&self) -> Option<i32> {
+        self.energy()
+            .map(|energy: i32| (energy as f64 * 0.45f64) as i32)
+    }
+}
+
+impl Item {
+    pub fn details(&self, id: u16) -> ItemDetails {
+        match self {
+            Self::ObjectInformation(object_information) => {
+                let mut type_and_category = object_information.type_and_category.split(' ');
+                let type_: &str = type_and_category.next().unwrap_or_default();
+                let category: String = match type_and_category
+                    .next()
+                    .and_then(|category: &str| category.parse::<i32>().ok())
+                    .and_then(category_name)
+                {
+                    Some(category) => category.to_string(),
+                    None => match type_ {
+                        "Arch" => "Artifact".to_string(),
+                        "Minerals" => "Mineral".to_string(),
+                        _ => type_.to_string(),
+                    },
+                };
+
+                ItemDetails {
+                    display_name: object_information.display_name.to_string(),
+                    description: Some(object_information.description.to_string()),
+                    category,
+                    price: Some(object_information.price),
+                    edibility: match object_information.edibility {
+                        -300i16 => None,
+                        edibility => Some(edibility),
+                    },
+                }
+            }
+            Self::BigCraftablesInformation(big_craftable_information) => ItemDetails {
+                display_name: big_craftable_information.display_name.to_string(),
+                description: Some(big_craftable_information.description.to_string()),
+                category: "Big Craftable".to_string(),
+                price: Some(big_craftable_information.price),
+                edibility: match big_craftable_information.edibility {
+                    -300i16 => None,
+                    edibility => Some(edibility),
+                },
+            },
+            Self::Furniture(furniture) => ItemDetails {
+                display_name: furniture.name.to_string(),
+                description: None,
+                category: format!("Furniture ({})", furniture.type_),
+                price: Some(furniture.price),
+                edibility: None,
+            },
+            Self::ClothingInformation(clothing_information) => ItemDetails {
+                display_name: self.name(id).replacen(
+                    clothing_information.name,
+                    clothing_information.display_name,
+                    1usize,
+                ),
+                description: Some(clothing_information.description.to_string()),
+                category: clothing_information.type_.to_string(),
+                price: Some(clothing_information.price),
+                edibility: None,
+            },
+            Self::Wallpaper(flooring) => ItemDetails {
+                display_name: self.name(id),
+                description: None,
+                category: if *flooring { "Flooring" } else { "Wallpaper" }.to_string(),
+                price: None,
+                edibility: None,
+            },
+            Self::Hats(hats) => ItemDetails {
+                display_name: hats.name.to_string(),
+                description: Some(hats.description.to_string()),
+                category: "Hat".to_string(),
+                price: None,
+                edibility: None,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct StockItem {
+    pub id: u16,
+    pub item: Item,
+    pub price: u32,
+    pub quantity: u8,
+}
+
+pub struct StockDay {
+    pub date: i32,
+    pub stock_items: Vec<StockItem>,
+}
+
+pub trait StockPredictor {
+    /// Returns `None` on days the shop doesn't open.
+    fn get_stock(configuration: &Configuration, date: i32) -> Result<Option<Vec<StockItem>>>;
+}
+
+pub fn get_stock_days<T: StockPredictor>(
+    configuration: &Configuration,
+    dates: Range<i32>,
+) -> Result<Vec<StockDay>> {
+    let mut stock_days: Vec<StockDay> = Vec::new();
+    for date in dates {
+        if let Some(stock_items) = T::get_stock(configuration, date)? {
+            stock_days.push(StockDay { date, stock_items });
+        }
+    }
+
+    Ok(stock_days)
+}
+
+/// The first `count` items on sale whose lowercase name contains `filter`, in date order.
+pub fn find_stock_items<T: StockPredictor>(
+    configuration: &Configuration,
+    dates: Range<i32>,
+    filter: &str,
+    count: usize,
+) -> Result<Vec<(i32, StockItem)>> {
+    let mut found: Vec<(i32, StockItem)> = Vec::new();
+    for date in dates {
+        if found.len() >= count {
+            break;
+        }
+
+        if let Some(stock_items) = T::get_stock(configuration, date)? {
+            found.extend(
+                stock_items
+                    .into_iter()
+                    .filter(|stock_item: &StockItem| {
+                        stock_item
+                            .item
+                            .name(stock_item.id)
+                            .to_lowercase()
+                            .contains(filter)
+                    })
+                    .map(|stock_item: StockItem| (date, stock_item)),
+            );
+        }
+    }
+    found.truncate(count);
+
+    Ok(found)
+}
+
+pub fn get_prng(platform: Platform, seed: i32) -> Result<Box<dyn Prng>> {
+    Ok(match platform {
+        Platform::Switch => Box::new(Jkiss::from_seed(seed)?),
+        Platform::PC => Box::new(MsCorLibRandom::from_seed(seed)?),
+    })
+}
+
+/// The PRNG used overnight (`Game1._newDayAfterFade`) before `date`, after its one `Next()` per
+/// day of the month. It is seeded using the total steps taken when going to bed.
+pub fn get_overnight_prng(
+    configuration: &Configuration,
+    date: i32,
+    steps_taken: u32,
+) -> Result<Box<dyn Prng>> {
+    let mut prng: Box<dyn Prng> = get_prng(
+        configuration.platform,
+        (configuration.seed / 100i32)
+            .wrapping_add(date.wrapping_mul(10i32))
+            .wrapping_add(1i32)
+            .wrapping_add(steps_taken as i32),
+    )?;
+
+    // `Next()` takes a single sample like this.
+    for _ in 0u8..=day_number(date) {
+        prng.gen_range(0i32..i32::MAX)?;
+    }
+
+    Ok(prng)
+}
+
+pub fn day_number(date: i32) -> u8 {
+    ((date - 1i32) % 28i32) as u8
+}
+
+pub fn day_name(date: i32) -> &'static str {
+    match day_number(date) % 7u8 {
+        0u8 => "Monday",
+        1u8 => "Tuesday",
+        2u8 => "Wednesday",
+        3u8 => "Thursday",
+        4u8 => "Friday",
+        5u8 => "Saturday",
+        6u8 => "Sunday",
+        _ => panic!(),
+    }
+}
+
+pub fn season_number(date: i32) -> u8 {
+    (((date - 1i32) / 28i32) % 4i32) as u8
+}
+
+pub fn season_name(date: i32) -> &'static str {
+    match season_number(date) {
+        0u8 => "Spring",
+        1u8 => "Summer",
+        2u8 => "Fall",
+        3u8 => "Winter",
+        _ => panic!(),
+    }
+}
+
+pub fn year_number(date: i32) -> u32 {
+    ((date - 1i32) / 112i32) as u32
+}
+
+pub fn format_date(date: i32) -> String {
+    if date <= 0 {
+        return "UNEXPECTED".to_string();
+    }
+
+    format!(
+        "{} {} {}, Year {}",
+        day_name(date),
+        season_name(date),
+        day_number(date) + 1u8,
+        year_number(date) + 1u32
+    )
+}
+
+/// Parses either a raw date number or a `season-day-year` string such as `fall-5-1`.
+pub fn parse_date(date: &str) -> Result<i32> {
+    if let Ok(date) = date.parse::<i32>() {
+        if date <= 0i32 {
+            bail!("Date {} is before the first day.", date);
+        }
+        return Ok(date);
+    }
+
+    let date_split: Vec<&str> = date.split("-").collect();
+    if date_split.len() != 3usize {
+        bail!("Date {} is not of the form season-day-year.", date);
+    }
+
+    let season: i32 = season_number_from_name(date_split[0usize])?;
+    let day: i32 = date_split[1usize]
+        .parse::<i32>()
+        .context("Error parsing day.")?;
+    let year: i32 = date_split[2usize]
+        .parse::<i32>()
+        .context("Error parsing year.")?;
+
+    date_from(season, day, year)
+}
+
+pub fn season_number_from_name(season: &str) -> Result<i32> {
+    Ok(match season.to_lowercase().as_str() {
+        "spring" => 0i32,
+        "summer" => 1i32,
+        "fall" => 2i32,
+        "winter" => 3i32,
+        _ => bail!("Unknown season {}.", season),
+    })
+}
+
+/// The inverse of `season_number`, `day_number` and `year_number`, except `day` and `year` start
+/// at 1.
+pub fn date_from(season: i32, day: i32, year: i32) -> Result<i32> {
+    if !(0i32..4i32).contains(&season) || !(1i32..=28i32).contains(&day) || year < 1i32 {
+        bail!(
+            "Season {}, day {}, year {} is out of range.",
+            season,
+            day,
+            year
+        );
+    }
+
+    (year - 1i32)
+        .checked_mul(112i32)
+        .and_then(|date: i32| date.checked_add(season * 28i32 + day))
+        .with_context(|| format!("Year {} is too large.", year))
+}
+
+pub fn get_random_furniture<P: Prng + ?Sized>(
+    prng: &mut P,
+    lower_bound: u16,
+    upper_bound: u16,
+) -> Result<u16> {
+    loop {
+        let id: u16 = prng.gen_range((lower_bound as i32)..(upper_bound as i32))? as u16;
+        if !FURNITURE.contains_key(&id) || FURNITURE_OFF_LIMIT.contains(&id) {
+            continue;
+        }
+        return Ok(id);
+    }
+}
+
+pub fn get_clothing_information(id: u16) -> Result<&'static ClothingInformation> {
+    Ok(match CLOTHING_INFORMATION.get(&id) {
+        Some(item) => item,
+        None => {
+            if id >= 1000u16 {
+                CLOTHING_INFORMATION
+                    .get(&(u16::MAX - 2u16))
+                    .context("Error getting clothing information.")?
+            } else {
+                CLOTHING_INFORMATION
+                    .get(&(u16::MAX - 1u16))
+                    .context("Error getting clothing information.")?
+            }
+        }
+    })
+}
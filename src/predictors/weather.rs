@@ -0,0 +1,111 @@
+use crate::predictors::util::{day_number, season_number};
+
+// Festival days of the month (starting at 1) by season, from `Utility.isFestivalDay`.
+const FESTIVALS: [[u8; 2usize]; 4usize] = [[13u8, 24u8], [11u8, 28u8], [16u8, 27u8], [8u8, 25u8]];
+
+/// The chance of each weather on a day. Only the date-driven rules are certain: in 1.5.6 the
+/// rest is rolled the night before with the step-seeded generator, after a varying number of
+/// other rolls, so it can only be given as chances.
+#[derive(Clone, Copy, PartialEq)]
+pub struct WeatherChances {
+    pub sunny: f64,
+    pub rain: f64,
+    pub storm: f64,
+    pub snow: f64,
+    pub wind: f64,
+    /// Why the weather is fixed, if it is.
+    pub reason: Option<&'static str>,
+}
+
+impl WeatherChances {
+    fn fixed(sunny: f64, rain: f64, reason: &'static str) -> Self {
+        Self {
+            sunny,
+            rain,
+            storm: 0f64,
+            snow: 0f64,
+            wind: 0f64,
+            reason: Some(reason),
+        }
+    }
+}
+
+pub fn is_festival(date: i32) -> bool {
+    FESTIVALS[season_number(date) as usize].contains(&(day_number(date) + 1u8))
+}
+
+/// Mirrors the weather for tomorrow in `Game1._newDayAfterFade`, which runs on the day before
+/// `date`.
+pub fn get_weather_chances(date: i32) -> WeatherChances {
+    // Rain and storms don't depend on the day before, so it only needs the one step back. This
+    // is exact after a fixed day, but otherwise treats the two days' rolls as independent.
+    let rained_yesterday: f64 = if date > 1i32 {
+        let yesterday: WeatherChances = weather_chances(date - 1i32, 0f64);
+        yesterday.rain + yesterday.storm
+    } else {
+        0f64
+    };
+
+    weather_chances(date, rained_yesterday)
+}
+
+/// `rained_yesterday` is the chance of rain or a storm the day before `date`, which rules out
+/// wind through `Game1.wasRainingYesterday`. Snow doesn't.
+fn weather_chances(date: i32, rained_yesterday: f64) -> WeatherChances {
+    let days_played: i32 = date - 1i32;
+
+    if day_number(date) == 0u8 {
+        return WeatherChances::fixed(1f64, 0f64, "The first day of a season is sunny.");
+    }
+    if is_festival(date) {
+        return WeatherChances::fixed(1f64, 0f64, "Festival.");
+    }
+    if days_played == 2i32 {
+        return WeatherChances::fixed(0f64, 1f64, "The third day is rainy.");
+    }
+
+    // The day the weather is rolled on.
+    let day_of_month: u8 = day_number(days_played) + 1u8;
+
+    let season: u8 = season_number(date);
+    let chance_to_rain: f64 = match season {
+        1u8 if day_of_month > 1u8 => 0.12f64 + (day_of_month as f32 * 0.003f32) as f64,
+        1u8 => 0f64,
+        3u8 => 0.63f64,
+        _ => 0.183f64,
+    };
+
+    // Rain turns to storms in summer, and in spring and fall once the first month has passed.
+    let late_storm: f64 = if day_of_month > 2u8 && days_played > 27i32 {
+        0.25f64
+    } else {
+        0f64
+    };
+    let chance_to_storm: f64 = match season {
+        1u8 => 0.85f64 + 0.15f64 * late_storm,
+        3u8 => 0f64,
+        _ => late_storm,
+    };
+
+    let chance_of_wind: f64 = match season {
+        0u8 if days_played > 2i32 => 0.2f64,
+        2u8 if days_played > 2i32 => 0.6f64,
+        _ => 0f64,
+    };
+
+    let (rain, snow): (f64, f64) = if season == 3u8 {
+        (0f64, chance_to_rain)
+    } else {
+        (chance_to_rain * (1f64 - chance_to_storm), 0f64)
+    };
+    let wind: f64 = (1f64 - chance_to_rain) * chance_of_wind * (1f64 - rained_yesterday);
+
+    WeatherChances {
+        sunny: 1f64 - chance_to_rain - wind,
+        rain,
+        storm: chance_to_rain * chance_to_storm,
+        snow,
+        wind,
+        reason: None,
+    }
+}
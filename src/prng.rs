@@ -3,6 +3,8 @@ use std::ops::Range;
 
 use anyhow::{bail, Result};
 
+use crate::configuration::Platform;
+
 pub trait Prng {
     fn from_seed(seed: i32) -> Result<Self>
     where
@@ -70,7 +72,7 @@ impl Prng for Jkiss {
 }
 
 // https://github.com/microsoft/referencesource/blob/master/mscorlib/system/random.cs
-// The generator doesn't seem to overflow, so we don't need to use Wrapping much.
+// The generator only overflows while seeding, so we don't need to use Wrapping much.
 pub struct MsCorLibRandom {
     seed: [i32; 56usize],
     n: usize,
@@ -133,7 +135,8 @@ impl Prng for MsCorLibRandom {
 
         for _ in 1usize..5usize {
             for i in 1usize..56usize {
-                s.seed[i] -= s.seed[1usize + (i + 30usize) % 55usize];
+                // Seeds near i32::MAX start mj negative, which can overflow here as it does in C#.
+                s.seed[i] = s.seed[i].wrapping_sub(s.seed[1usize + (i + 30usize) % 55usize]);
                 if s.seed[i] < 0i32 {
                     s.seed[i] += i32::MAX;
                 }
@@ -169,3 +172,102 @@ impl Prng for MsCorLibRandom {
         Ok(self.gen() as f64 * (1f64 / i32::MAX as f64))
     }
 }
+
+const XXHASH32_PRIME_1: Wrapping<u32> = Wrapping(2654435761u32);
+const XXHASH32_PRIME_2: Wrapping<u32> = Wrapping(2246822519u32);
+const XXHASH32_PRIME_3: Wrapping<u32> = Wrapping(3266489917u32);
+const XXHASH32_PRIME_4: Wrapping<u32> = Wrapping(668265263u32);
+const XXHASH32_PRIME_5: Wrapping<u32> = Wrapping(374761393u32);
+
+fn xxhash32_round(accumulator: Wrapping<u32>, lane: &[u8]) -> Wrapping<u32> {
+    let lane: Wrapping<u32> = Wrapping(u32::from_le_bytes([lane[0], lane[1], lane[2], lane[3]]));
+    Wrapping((accumulator + lane * XXHASH32_PRIME_2).0.rotate_left(13u32)) * XXHASH32_PRIME_1
+}
+
+// https://github.com/Cyan4973/xxHash/blob/dev/doc/xxhash_spec.md
+/// xxHash32 with a seed of 0, which is what 1.6 hashes seeds with.
+pub fn xxhash32(data: &[u8]) -> u32 {
+    let mut stripes = data.chunks_exact(16usize);
+    let mut hash: Wrapping<u32> = if data.len() >= 16usize {
+        let mut accumulators: [Wrapping<u32>; 4usize] = [
+            XXHASH32_PRIME_1 + XXHASH32_PRIME_2,
+            XXHASH32_PRIME_2,
+            Wrapping(0u32),
+            Wrapping(0u32) - XXHASH32_PRIME_1,
+        ];
+        for stripe in &mut stripes {
+            for (i, accumulator) in accumulators.iter_mut().enumerate() {
+                *accumulator = xxhash32_round(*accumulator, &stripe[i * 4usize..]);
+            }
+        }
+
+        Wrapping(accumulators[0usize].0.rotate_left(1u32))
+            + Wrapping(accumulators[1usize].0.rotate_left(7u32))
+            + Wrapping(accumulators[2usize].0.rotate_left(12u32))
+            + Wrapping(accumulators[3usize].0.rotate_left(18u32))
+    } else {
+        XXHASH32_PRIME_5
+    };
+
+    hash += Wrapping(data.len() as u32);
+
+    let mut lanes = stripes.remainder().chunks_exact(4usize);
+    for lane in &mut lanes {
+        let lane: Wrapping<u32> =
+            Wrapping(u32::from_le_bytes([lane[0], lane[1], lane[2], lane[3]]));
+        hash = Wrapping((hash + lane * XXHASH32_PRIME_3).0.rotate_left(17u32)) * XXHASH32_PRIME_4;
+    }
+    for byte in lanes.remainder() {
+        hash = Wrapping(
+            (hash + Wrapping(*byte as u32) * XXHASH32_PRIME_5)
+                .0
+                .rotate_left(11u32),
+        ) * XXHASH32_PRIME_1;
+    }
+
+    hash ^= hash >> 15usize;
+    hash *= XXHASH32_PRIME_2;
+    hash ^= hash >> 13usize;
+    hash *= XXHASH32_PRIME_3;
+    hash ^= hash >> 16usize;
+
+    hash.0
+}
+
+/// Reproduces 1.6's `Utility.CreateRandomSeed`, which takes up to five values with the rest
+/// defaulting to 0. With legacy random (a save option) the values are summed, otherwise their
+/// 32-bit truncations are hashed.
+pub fn create_random_seed(values: &[f64], legacy_random: bool) -> Result<i32> {
+    if values.len() > 5usize {
+        bail!("More than five values parsed to create_random_seed().");
+    }
+
+    let mut seeds: [f64; 5usize] = [0f64; 5usize];
+    for (seed, value) in seeds.iter_mut().zip(values) {
+        *seed = value % i32::MAX as f64;
+    }
+
+    if legacy_random {
+        Ok((seeds.iter().sum::<f64>() % i32::MAX as f64) as i32)
+    } else {
+        let mut data: [u8; 20usize] = [0u8; 20usize];
+        for (bytes, seed) in data.chunks_exact_mut(4usize).zip(seeds) {
+            bytes.copy_from_slice(&(seed as i32).to_le_bytes());
+        }
+
+        Ok(xxhash32(&data) as i32)
+    }
+}
+
+/// Reproduces 1.6's `Utility.CreateRandom`, with the platform's `Prng`.
+pub fn create_random(
+    platform: Platform,
+    values: &[f64],
+    legacy_random: bool,
+) -> Result<Box<dyn Prng>> {
+    let seed: i32 = create_random_seed(values, legacy_random)?;
+    Ok(match platform {
+        Platform::Switch => Box::new(Jkiss::from_seed(seed)?),
+        Platform::PC => Box::new(MsCorLibRandom::from_seed(seed)?),
+    })
+}
@@ -0,0 +1,29 @@
+use anyhow::{Context, Result};
+
+use crate::configuration::Configuration;
+use crate::location::{configuration_from_query, configuration_to_query};
+use crate::storage::LocalStorage;
+
+// Each profile is stored under its own key, with the configuration in the URL query format.
+const PROFILES: LocalStorage<Configuration> = LocalStorage {
+    prefix: "profile:",
+    description: "profile",
+    serialise: configuration_to_query,
+    deserialise: configuration_from_query,
+};
+
+pub fn profile_names() -> Vec<String> {
+    PROFILES.names()
+}
+
+pub fn load_profile(name: &str) -> Result<Configuration> {
+    PROFILES.load(name)?.context("Error getting profile.")
+}
+
+pub fn save_profile(name: &str, configuration: &Configuration) -> Result<()> {
+    PROFILES.save(name, configuration)
+}
+
+pub fn delete_profile(name: &str) -> Result<()> {
+    PROFILES.delete(name)
+}
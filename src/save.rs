@@ -0,0 +1,241 @@
+use std::cmp::min;
+
+use anyhow::{bail, Context, Result};
+use roxmltree::{Document, Node};
+
+use crate::configuration::{Configuration, Platform};
+use crate::predictors::bundles::BundleSlot;
+use crate::predictors::util::{date_from, season_number_from_name};
+
+fn child<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+    node.children()
+        .find(|child: &Node| child.tag_name().name().eq_ignore_ascii_case(name))
+}
+
+fn child_text<'a>(node: Node<'a, '_>, name: &str) -> Result<&'a str> {
+    child(node, name)
+        .and_then(|child: Node| child.text())
+        .with_context(|| format!("Error getting {} from save.", name))
+}
+
+fn child_strings<'a>(node: Node<'a, '_>, name: &str) -> Vec<&'a str> {
+    match child(node, name) {
+        Some(child) => child
+            .children()
+            .filter_map(|string: Node| string.text())
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+fn stat(player: Node, name: &str) -> Result<u32> {
+    let stats: Node = child(player, "stats").context("Error getting stats from save.")?;
+
+    // 1.5 stores stats as fields, 1.6 as a dictionary of values.
+    let stat: &str = match child(stats, name) {
+        Some(stat) => stat.text().unwrap_or("0"),
+        None => child(stats, "Values")
+            .and_then(|values: Node| {
+                values.children().find_map(|item: Node| {
+                    let key: &str = child(child(item, "key")?, "string")?.text()?;
+                    if key == name {
+                        child(child(item, "value")?, "unsignedInt")?.text()
+                    } else {
+                        None
+                    }
+                })
+            })
+            .unwrap_or("0"),
+    };
+
+    stat.parse::<u32>()
+        .with_context(|| format!("Error parsing {}.", name))
+}
+
+/// Qi's crop is the special order with the `DROP_QI_BEANS` rule.
+fn qis_crop(save_game: Node) -> bool {
+    let special_orders: Node = match child(save_game, "specialOrders") {
+        Some(special_orders) => special_orders,
+        None => return false,
+    };
+
+    special_orders.children().any(|special_order: Node| {
+        let is_qis_crop: bool = child_text(special_order, "specialRule")
+            .is_ok_and(|special_rule: &str| special_rule.contains("DROP_QI_BEANS"))
+            || child_text(special_order, "questKey")
+                .is_ok_and(|quest_key: &str| quest_key == "QiChallenge2");
+        let in_progress: bool = child_text(special_order, "questState")
+            .is_ok_and(|quest_state: &str| quest_state == "InProgress");
+
+        is_qis_crop && in_progress
+    })
+}
+
+/// Mirrors `Farmer.hasOrWillReceiveMail`.
+fn has_or_will_receive_mail(player: Node, mail: &str) -> bool {
+    ["mailReceived", "mailForTomorrow", "mailbox"]
+        .iter()
+        .any(|name: &&str| child_strings(player, name).contains(&mail))
+}
+
+fn save_game<'a, 'input>(document: &'a Document<'input>) -> Result<Node<'a, 'input>> {
+    let save_game: Node = document.root_element();
+    if save_game.tag_name().name() != "SaveGame" {
+        bail!(
+            "Expected a farm save, found {}. SaveGameInfo doesn't contain the seed.",
+            save_game.tag_name().name()
+        );
+    }
+
+    Ok(save_game)
+}
+
+/// Reads the configuration from a farm save file (the one named after the farm, not
+/// `SaveGameInfo`, which doesn't contain the seed). Saves don't record the platform.
+pub fn configuration_from_save(save: &str, platform: Platform) -> Result<Configuration> {
+    let document: Document = Document::parse(save).context("Error parsing save.")?;
+    let save_game: Node = save_game(&document)?;
+    let player: Node = child(save_game, "player").context("Error getting player from save.")?;
+
+    // Only 1.5 is predicted, so refuse later saves rather than predict them wrongly. Saves from
+    // before the version was recorded are all older.
+    if let Some(game_version) = child(save_game, "gameVersion").and_then(|node| node.text()) {
+        if game_version != "1.5" && !game_version.starts_with("1.5.") {
+            bail!("Saves from version {} are not supported.", game_version);
+        }
+    }
+
+    // The game casts the 64-bit ID to an int, keeping the low 32 bits.
+    let seed: i32 = child_text(save_game, "uniqueIDForThisGame")?
+        .parse::<u64>()
+        .context("Error parsing seed.")? as i32;
+
+    let date: i32 = date_from(
+        season_number_from_name(child_text(save_game, "currentSeason")?)?,
+        child_text(save_game, "dayOfMonth")?
+            .parse::<i32>()
+            .context("Error parsing day.")?,
+        child_text(save_game, "year")?
+            .parse::<i32>()
+            .context("Error parsing year.")?,
+    )?;
+
+    let mine_level: u8 = min(
+        child_text(player, "deepestMineLevel")?
+            .parse::<i32>()
+            .context("Error parsing deepest mine level.")?,
+        u8::MAX as i32,
+    ) as u8;
+
+    // -1 both when the option is off and once the guarantee has been made, so only a count says
+    // whether it's on. Saves from before 1.5 don't have it.
+    let visits_until_y1_guarantee: Option<i32> =
+        match child(save_game, "visitsUntilY1Guarantee").and_then(|node| node.text()) {
+            Some(visits) => Some(
+                visits
+                    .parse::<i32>()
+                    .context("Error parsing visits until year 1 guarantee.")?,
+            ),
+            None => None,
+        };
+
+    Ok(Configuration {
+        platform,
+        seed,
+        date: Some(date),
+        geodes_cracked: Some(min(stat(player, "geodesCracked")?, u16::MAX as u32) as u16),
+        mine_level: Some(mine_level),
+        qis_crop: Some(qis_crop(save_game)),
+        golden_helmet: Some(has_or_will_receive_mail(player, "goldenCoconutHat")),
+        year_one_completable: visits_until_y1_guarantee
+            .filter(|visits: &i32| *visits >= 0i32)
+            .map(|_| true),
+        visits_until_y1_guarantee,
+        player_id: Some(
+            child_text(player, "UniqueMultiplayerID")?
+                .parse::<i64>()
+                .context("Error parsing player ID.")?,
+        ),
+        // Saved at the start of the day.
+        steps_taken: Some(stat(player, "stepsTaken")?),
+        trash_cans_checked: Some(stat(player, "trashCansChecked")?),
+    })
+}
+
+fn location<'a, 'input>(save_game: Node<'a, 'input>, type_: &str) -> Option<Node<'a, 'input>> {
+    child(save_game, "locations").and_then(|locations: Node| {
+        locations.children().find(|location: &Node| {
+            location
+                .attributes()
+                .any(|attribute| attribute.name() == "type" && attribute.value() == type_)
+        })
+    })
+}
+
+/// Reads the IDs of the items donated to the museum from a farm save file.
+pub fn museum_from_save(save: &str) -> Result<Vec<u16>> {
+    let document: Document = Document::parse(save).context("Error parsing save.")?;
+    let save_game: Node = save_game(&document)?;
+
+    let museum: Node =
+        location(save_game, "LibraryMuseum").context("Error getting museum from save.")?;
+
+    let mut donated: Vec<u16> = Vec::new();
+    if let Some(museum_pieces) = child(museum, "museumPieces") {
+        for item in museum_pieces
+            .children()
+            .filter(|item: &Node| item.is_element())
+        {
+            // 1.5 stores the ID as an int, 1.6 as a qualified or unqualified string.
+            let value: Node = child(item, "value").context("Error getting museum piece.")?;
+            let id: &str = value
+                .children()
+                .find_map(|id: Node| id.text().filter(|text: &&str| !text.trim().is_empty()))
+                .context("Error getting museum piece.")?;
+            donated.push(
+                id.trim_start_matches("(O)")
+                    .parse::<u16>()
+                    .context("Error parsing museum piece.")?,
+            );
+        }
+    }
+    donated.sort();
+    donated.dedup();
+
+    Ok(donated)
+}
+
+/// Reads the completed bundle slots from a farm save file.
+pub fn bundles_from_save(save: &str) -> Result<Vec<BundleSlot>> {
+    let document: Document = Document::parse(save).context("Error parsing save.")?;
+    let save_game: Node = save_game(&document)?;
+
+    let community_center: Node = location(save_game, "CommunityCenter")
+        .context("Error getting community center from save.")?;
+
+    let mut completed: Vec<BundleSlot> = Vec::new();
+    if let Some(bundles) = child(community_center, "bundles") {
+        for item in bundles.children().filter(|item: &Node| item.is_element()) {
+            let index: u16 = child(item, "key")
+                .and_then(|key: Node| child_text(key, "int").ok())
+                .context("Error getting bundle from save.")?
+                .parse::<u16>()
+                .context("Error parsing bundle.")?;
+            let slots: Node = child(item, "value")
+                .and_then(|value: Node| child(value, "ArrayOfBoolean"))
+                .context("Error getting bundle from save.")?;
+            for (slot, boolean) in slots
+                .children()
+                .filter(|boolean: &Node| boolean.is_element())
+                .enumerate()
+            {
+                if boolean.text() == Some("true") {
+                    completed.push((index, slot as u8));
+                }
+            }
+        }
+    }
+    completed.sort();
+
+    Ok(completed)
+}
@@ -0,0 +1,236 @@
+use std::cmp::min;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicI64, AtomicUsize, Ordering};
+use std::thread;
+
+use anyhow::{bail, Context, Error, Result};
+
+use crate::configuration::{Configuration, Platform};
+use crate::predictors::geodes::{generate_geode, GeodeType};
+use crate::predictors::traveling_cart::{generate_stock, has_y1_guarantee, is_open};
+use crate::predictors::util::{parse_date, StockItem};
+use crate::prng::{Jkiss, MsCorLibRandom, Prng};
+
+const SCAN_CHUNK_SIZE: i64 = 65536i64;
+
+#[derive(Clone)]
+pub enum SeedPredicate {
+    /// The traveling cart sells the item on some day from the configured date (or the first day)
+    /// up to, but not including, `before`.
+    TravelingCartSells { item_name: String, before: i32 },
+    /// Cracking the geode type yields the item, where `geodes_cracked` is the count shown in the
+    /// geodes table.
+    GeodeYields {
+        geode_type: GeodeType,
+        geodes_cracked: i32,
+        item_name: String,
+    },
+}
+
+impl SeedPredicate {
+    pub fn holds(&self, configuration: &Configuration) -> Result<bool> {
+        match configuration.platform {
+            Platform::PC => self.holds_with::<MsCorLibRandom>(configuration, &mut Vec::new()),
+            Platform::Switch => self.holds_with::<Jkiss>(configuration, &mut Vec::new()),
+        }
+    }
+
+    /// Monomorphised check which reuses `stock_items` rather than allocating per day.
+    fn holds_with<P: Prng>(
+        &self,
+        configuration: &Configuration,
+        stock_items: &mut Vec<StockItem>,
+    ) -> Result<bool> {
+        match self {
+            Self::TravelingCartSells { item_name, before } => {
+                for date in configuration.date.unwrap_or(1i32)..*before {
+                    if !is_open(date) {
+                        continue;
+                    }
+                    generate_stock(
+                        &mut P::from_seed(configuration.seed.wrapping_add(date))?,
+                        configuration.platform,
+                        date,
+                        has_y1_guarantee(configuration, date),
+                        stock_items,
+                    )?;
+                    if stock_items.iter().any(|stock_item: &StockItem| {
+                        stock_item.item.name_matches(stock_item.id, item_name)
+                    }) {
+                        return Ok(true);
+                    }
+                }
+
+                Ok(false)
+            }
+            Self::GeodeYields {
+                geode_type,
+                geodes_cracked,
+                item_name,
+            } => {
+                let geode = generate_geode(
+                    &mut P::from_seed((configuration.seed / 2i32).wrapping_add(*geodes_cracked))?,
+                    configuration,
+                    *geode_type,
+                    *geodes_cracked,
+                )?;
+                Ok(geode.item.name_matches(geode.id, item_name))
+            }
+        }
+    }
+}
+
+/// Parses `cart:<item>:<before date>` or `geode:<geode type>:<geodes cracked>:<item>`.
+impl FromStr for SeedPredicate {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let predicate_split: Vec<&str> = s.split(":").collect();
+        Ok(match predicate_split.as_slice() {
+            ["cart", item_name, before] => Self::TravelingCartSells {
+                item_name: item_name.to_string(),
+                before: parse_date(before)?,
+            },
+            ["geode", geode_type, geodes_cracked, item_name] => Self::GeodeYields {
+                geode_type: geode_type.parse::<GeodeType>()?,
+                geodes_cracked: geodes_cracked
+                    .parse::<i32>()
+                    .context("Error parsing geodes cracked.")?,
+                item_name: item_name.to_string(),
+            },
+            _ => bail!("Unknown predicate {}.", s),
+        })
+    }
+}
+
+pub fn seed_matches(configuration: &Configuration, predicates: &[SeedPredicate]) -> Result<bool> {
+    for predicate in predicates {
+        if !predicate.holds(configuration)? {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+/// Lazily yields the seeds for which every predicate holds. Everything except the seed is taken
+/// from `configuration`.
+pub fn search_seeds<'a>(
+    configuration: &'a Configuration,
+    seeds: RangeInclusive<i32>,
+    predicates: &'a [SeedPredicate],
+) -> impl Iterator<Item = Result<i32>> + 'a {
+    seeds.filter_map(move |seed: i32| {
+        let configuration: Configuration = Configuration {
+            seed,
+            ..configuration.clone()
+        };
+        match seed_matches(&configuration, predicates) {
+            Ok(true) => Some(Ok(seed)),
+            Ok(false) => None,
+            Err(error) => Some(Err(error)),
+        }
+    })
+}
+
+fn scan_chunks<P: Prng>(
+    configuration: &Configuration,
+    predicates: &[SeedPredicate],
+    end: i64,
+    next_chunk: &AtomicI64,
+    found_count: &AtomicUsize,
+    limit: usize,
+) -> Result<Vec<i32>> {
+    let mut configuration: Configuration = configuration.clone();
+    let mut stock_items: Vec<StockItem> = Vec::new();
+    let mut found: Vec<i32> = Vec::new();
+
+    while found_count.load(Ordering::Relaxed) < limit {
+        let chunk_start: i64 = next_chunk.fetch_add(SCAN_CHUNK_SIZE, Ordering::Relaxed);
+        if chunk_start >= end {
+            break;
+        }
+
+        'seeds: for seed in chunk_start..min(chunk_start + SCAN_CHUNK_SIZE, end) {
+            // Checked per seed, as a chunk takes a while with cart predicates.
+            if found_count.load(Ordering::Relaxed) >= limit {
+                break;
+            }
+
+            configuration.seed = seed as i32;
+            for predicate in predicates {
+                if !predicate.holds_with::<P>(&configuration, &mut stock_items)? {
+                    continue 'seeds;
+                }
+            }
+
+            found.push(seed as i32);
+            if found_count.fetch_add(1usize, Ordering::Relaxed) + 1usize >= limit {
+                break;
+            }
+        }
+    }
+
+    Ok(found)
+}
+
+/// Multi-threaded version of `search_seeds` for large ranges, returning the matching seeds in
+/// ascending order. Threads claim chunks of seeds in order and stop once `limit` seeds have been
+/// found between them, so with a limit the result is not guaranteed to be the lowest seeds.
+pub fn scan_seeds(
+    configuration: &Configuration,
+    seeds: RangeInclusive<i32>,
+    predicates: &[SeedPredicate],
+    threads: usize,
+    limit: usize,
+) -> Result<Vec<i32>> {
+    // Geodes only need one PRNG, so check them first to reject most seeds early.
+    let mut predicates: Vec<SeedPredicate> = predicates.to_vec();
+    predicates.sort_by_key(|predicate: &SeedPredicate| {
+        matches!(predicate, SeedPredicate::TravelingCartSells { .. })
+    });
+
+    let end: i64 = *seeds.end() as i64 + 1i64;
+    let next_chunk: AtomicI64 = AtomicI64::new(*seeds.start() as i64);
+    let found_count: AtomicUsize = AtomicUsize::new(0usize);
+
+    let results: Vec<Result<Vec<i32>>> = thread::scope(|scope| {
+        let handles: Vec<_> = (0usize..threads.max(1usize))
+            .map(|_| {
+                scope.spawn(|| match configuration.platform {
+                    Platform::PC => scan_chunks::<MsCorLibRandom>(
+                        configuration,
+                        &predicates,
+                        end,
+                        &next_chunk,
+                        &found_count,
+                        limit,
+                    ),
+                    Platform::Switch => scan_chunks::<Jkiss>(
+                        configuration,
+                        &predicates,
+                        end,
+                        &next_chunk,
+                        &found_count,
+                        limit,
+                    ),
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("Seed scanning thread panicked."))
+            .collect()
+    });
+
+    let mut found: Vec<i32> = Vec::new();
+    for result in results {
+        found.extend(result?);
+    }
+    found.sort_unstable();
+    found.truncate(limit);
+
+    Ok(found)
+}
@@ -0,0 +1,70 @@
+use anyhow::{anyhow, Context, Result};
+use gloo::utils::window;
+use web_sys::Storage;
+
+fn storage() -> Result<Storage> {
+    window()
+        .local_storage()
+        .ok()
+        .flatten()
+        .context("Error getting local storage.")
+}
+
+/// Values kept in local storage, each under `prefix` followed by its name, as the string
+/// `serialise` returns. Either function returns `None` if the value can't be converted.
+pub struct LocalStorage<T> {
+    pub prefix: &'static str,
+    /// What is stored, for error messages.
+    pub description: &'static str,
+    pub serialise: fn(&T) -> Option<String>,
+    pub deserialise: fn(&str) -> Option<T>,
+}
+
+impl<T> LocalStorage<T> {
+    fn key(&self, name: &str) -> String {
+        format!("{}{}", self.prefix, name)
+    }
+
+    /// The names of the stored values, sorted.
+    pub fn names(&self) -> Vec<String> {
+        let storage: Storage = match storage() {
+            Ok(storage) => storage,
+            Err(_) => return Vec::new(),
+        };
+
+        let mut names: Vec<String> = (0u32..storage.length().unwrap_or(0u32))
+            .filter_map(|index: u32| storage.key(index).ok().flatten())
+            .filter_map(|key: String| key.strip_prefix(self.prefix).map(str::to_string))
+            .collect();
+        names.sort();
+
+        names
+    }
+
+    /// Returns `None` if nothing is stored under the name.
+    pub fn load(&self, name: &str) -> Result<Option<T>> {
+        match storage()?.get_item(&self.key(name)).ok().flatten() {
+            Some(value) => {
+                Ok(Some((self.deserialise)(&value).with_context(|| {
+                    format!("Error parsing {}.", self.description)
+                })?))
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub fn save(&self, name: &str, value: &T) -> Result<()> {
+        let value: String = (self.serialise)(value)
+            .with_context(|| format!("Error encoding {}.", self.description))?;
+
+        storage()?
+            .set_item(&self.key(name), &value)
+            .map_err(|_| anyhow!("Error saving {}.", self.description))
+    }
+
+    pub fn delete(&self, name: &str) -> Result<()> {
+        storage()?
+            .remove_item(&self.key(name))
+            .map_err(|_| anyhow!("Error deleting {}.", self.description))
+    }
+}
@@ -0,0 +1,89 @@
+use stardew_predictor::codegen::{BUNDLES, OBJECT_INFORMATION};
+use stardew_predictor::predictors::bundles::{
+    get_bundles, get_open_requirements, is_bundle_complete, OpenRequirement,
+};
+use stardew_predictor::predictors::util::Item;
+use stardew_predictor::save::bundles_from_save;
+
+fn open_bundles(id: u16, quantity: u8, completed: &[(u16, u8)]) -> Vec<&'static str> {
+    get_open_requirements(
+        &Item::ObjectInformation(&OBJECT_INFORMATION[&id]),
+        id,
+        quantity,
+        completed,
+    )
+    .iter()
+    .map(|open_requirement: &OpenRequirement| open_requirement.bundle.name)
+    .collect()
+}
+
+#[test]
+fn bundles() {
+    assert_eq!(get_bundles().len(), 31usize);
+
+    let the_missing = &BUNDLES[&36u16];
+    assert_eq!(the_missing.name, "The Missing");
+    assert_eq!(the_missing.items.len(), 6usize);
+    assert_eq!(the_missing.count, 5u8);
+
+    // Every bundle item except the vault's gold is an object.
+    for (_, bundle) in get_bundles() {
+        assert!(bundle.count as usize <= bundle.items.len());
+        for bundle_item in bundle.items {
+            assert!(
+                bundle_item.id == -1i32
+                    || OBJECT_INFORMATION.contains_key(&(bundle_item.id as u16))
+            );
+        }
+    }
+}
+
+#[test]
+fn quality_and_quantity() {
+    // The Missing needs silver wine, which shops don't sell.
+    assert_eq!(open_bundles(348u16, 1u8, &[]), vec!["Enchanter's"]);
+    // Construction needs 99 wood.
+    assert!(open_bundles(388u16, 5u8, &[]).is_empty());
+    // Fodder needs 10 wheat.
+    assert_eq!(open_bundles(262u16, 10u8, &[]), vec!["Fodder"]);
+    assert!(open_bundles(262u16, 9u8, &[]).is_empty());
+}
+
+#[test]
+fn completed_slots() {
+    // Enchanter's wine slot.
+    assert!(open_bundles(348u16, 1u8, &[(33u16, 1u8)]).is_empty());
+
+    // The Missing is complete with five of its six slots, so Caviar is no longer needed.
+    assert_eq!(open_bundles(445u16, 1u8, &[]), vec!["The Missing"]);
+    let completed: Vec<(u16, u8)> = (0u8..5u8).map(|slot: u8| (36u16, slot)).collect();
+    assert!(is_bundle_complete(36u16, &BUNDLES[&36u16], &completed));
+    assert!(open_bundles(445u16, 1u8, &completed).is_empty());
+}
+
+#[test]
+fn bundles_from_save_slots() {
+    let save: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<SaveGame xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xmlns:xsd="http://www.w3.org/2001/XMLSchema">
+  <locations>
+    <GameLocation xsi:type="CommunityCenter">
+      <bundles>
+        <item>
+          <key><int>0</int></key>
+          <value><ArrayOfBoolean><boolean>true</boolean><boolean>false</boolean><boolean>true</boolean></ArrayOfBoolean></value>
+        </item>
+        <item>
+          <key><int>36</int></key>
+          <value><ArrayOfBoolean><boolean>false</boolean><boolean>true</boolean></ArrayOfBoolean></value>
+        </item>
+      </bundles>
+    </GameLocation>
+  </locations>
+</SaveGame>"#;
+
+    assert_eq!(
+        bundles_from_save(save).unwrap(),
+        vec![(0u16, 0u8), (0u16, 2u8), (36u16, 1u8)]
+    );
+    assert!(bundles_from_save("<SaveGame><locations /></SaveGame>").is_err());
+}
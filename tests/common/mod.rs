@@ -0,0 +1,20 @@
+use stardew_predictor::configuration::{Configuration, Platform};
+
+/// Seed 12345 without any optional parameters. Tests set the fields they need with
+/// struct update syntax.
+pub fn configuration(platform: Platform) -> Configuration {
+    Configuration {
+        platform,
+        seed: 12345i32,
+        date: None,
+        geodes_cracked: None,
+        mine_level: None,
+        qis_crop: None,
+        golden_helmet: None,
+        year_one_completable: None,
+        visits_until_y1_guarantee: None,
+        player_id: None,
+        steps_taken: None,
+        trash_cans_checked: None,
+    }
+}
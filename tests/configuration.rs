@@ -0,0 +1,36 @@
+use stardew_predictor::configuration::{Configuration, Platform};
+use stardew_predictor::save::configuration_from_save;
+
+fn save(game_version: &str) -> String {
+    format!(
+        "<SaveGame><player><UniqueMultiplayerID>-6789</UniqueMultiplayerID>\
+        <deepestMineLevel>0</deepestMineLevel><stats /></player>\
+        <currentSeason>spring</currentSeason><dayOfMonth>3</dayOfMonth><year>1</year>\
+        <uniqueIDForThisGame>12345</uniqueIDForThisGame>{}</SaveGame>",
+        game_version
+    )
+}
+
+#[test]
+fn save_game_version() {
+    // Every 1.5 patch, and saves from before the version was recorded.
+    for game_version in [
+        "<gameVersion>1.5</gameVersion>",
+        "<gameVersion>1.5.4</gameVersion>",
+        "<gameVersion>1.5.6</gameVersion>",
+        "",
+    ] {
+        let configuration: Configuration =
+            configuration_from_save(&save(game_version), Platform::PC).unwrap();
+        assert_eq!(configuration.seed, 12345i32);
+        assert_eq!(configuration.date, Some(3i32));
+    }
+
+    for game_version in [
+        "<gameVersion>1.6</gameVersion>",
+        "<gameVersion>1.6.8</gameVersion>",
+        "<gameVersion>1.56</gameVersion>",
+    ] {
+        assert!(configuration_from_save(&save(game_version), Platform::PC).is_err());
+    }
+}
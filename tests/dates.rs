@@ -0,0 +1,28 @@
+use stardew_predictor::predictors::util::{date_from, parse_date};
+
+#[test]
+fn parse_known_dates() {
+    assert_eq!(parse_date("5").unwrap(), 5i32);
+    assert_eq!(parse_date("spring-1-1").unwrap(), 1i32);
+    assert_eq!(parse_date("fall-5-1").unwrap(), 61i32);
+    assert_eq!(parse_date("Winter-28-2").unwrap(), 224i32);
+}
+
+#[test]
+fn parse_out_of_range_dates() {
+    for date in [
+        "0",
+        "-3",
+        "spring-0-1",
+        "spring-29-1",
+        "spring-1-0",
+        "autumn-1-1",
+    ] {
+        assert!(parse_date(date).is_err(), "{}", date);
+    }
+
+    // Past i32::MAX, rather than overflowing.
+    assert!(parse_date("spring-1-30000000").is_err());
+    assert!(date_from(3i32, 28i32, i32::MAX).is_err());
+    assert_eq!(date_from(0i32, 1i32, 19173962i32).unwrap(), 2147483633i32);
+}
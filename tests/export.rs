@@ -0,0 +1,164 @@
+mod common;
+
+use stardew_predictor::configuration::{Configuration, Platform};
+use stardew_predictor::export::{
+    export_farm_events, export_geodes, export_luck, export_mines, export_stock,
+    export_stock_matching, export_tables, export_trash_cans, export_weather, ExportFormat,
+    ExportTable, ExportValue,
+};
+use stardew_predictor::predictors::traveling_cart::TravelingCartPredictor;
+use stardew_predictor::predictors::util::{get_stock_days, StockDay, StockItem};
+
+fn table() -> ExportTable {
+    ExportTable {
+        name: "Test table".to_string(),
+        columns: vec!["name", "count", "chance", "found", "note"],
+        rows: vec![
+            vec![
+                ExportValue::from("Plain"),
+                ExportValue::Integer(-3i64),
+                ExportValue::Float(0.5f64),
+                ExportValue::Bool(true),
+                ExportValue::None,
+            ],
+            vec![
+                ExportValue::from("Comma, \"quoted\"\nline"),
+                ExportValue::Integer(7i64),
+                ExportValue::Float(f64::NAN),
+                ExportValue::Bool(false),
+                ExportValue::from("\t\\"),
+            ],
+        ],
+    }
+}
+
+#[test]
+fn csv() {
+    assert_eq!(
+        table().to_csv(),
+        "name,count,chance,found,note\nPlain,-3,0.5,true,\n\"Comma, \"\"quoted\"\"\nline\",7,NaN,false,\t\\\n"
+    );
+}
+
+#[test]
+fn json() {
+    assert_eq!(
+        table().to_json(),
+        "[{\"name\":\"Plain\",\"count\":-3,\"chance\":0.5,\"found\":true,\"note\":null},{\"name\":\"Comma, \\\"quoted\\\"\\nline\",\"count\":7,\"chance\":null,\"found\":false,\"note\":\"\\t\\\\\"}]"
+    );
+}
+
+#[test]
+fn tables() {
+    let tables: Vec<ExportTable> = vec![table(), table()];
+    assert_eq!(
+        export_tables(&tables, ExportFormat::Csv),
+        format!("{}\n{}", table().to_csv(), table().to_csv())
+    );
+    assert_eq!(
+        export_tables(&tables, ExportFormat::Json),
+        format!(
+            "{{\"Test table\":{},\"Test table\":{}}}",
+            table().to_json(),
+            table().to_json()
+        )
+    );
+    assert_eq!(table().file_name(ExportFormat::Csv), "test-table.csv");
+    assert_eq!(table().file_name(ExportFormat::Json), "test-table.json");
+    assert!("JSON".parse::<ExportFormat>().unwrap() == ExportFormat::Json);
+    assert!("xml".parse::<ExportFormat>().is_err());
+}
+
+#[test]
+fn predictor_tables() {
+    let configuration: Configuration = common::configuration(Platform::PC);
+
+    let stock: ExportTable =
+        export_stock::<TravelingCartPredictor>("Traveling Cart", &configuration, 1i32..29i32)
+            .unwrap();
+    let stock_days: Vec<StockDay> =
+        get_stock_days::<TravelingCartPredictor>(&configuration, 1i32..29i32).unwrap();
+    assert_eq!(
+        stock.rows.len(),
+        stock_days
+            .iter()
+            .map(|stock_day: &StockDay| stock_day.stock_items.len())
+            .sum::<usize>()
+    );
+
+    assert_eq!(
+        export_geodes(&configuration, 1i32..11i32)
+            .unwrap()
+            .rows
+            .len(),
+        60usize
+    );
+    assert_eq!(
+        export_luck(&configuration, 0u32..5u32).unwrap().rows.len(),
+        5usize
+    );
+    assert!(export_luck(
+        &Configuration {
+            date: Some(i32::MAX),
+            ..configuration.clone()
+        },
+        0u32..5u32
+    )
+    .is_err());
+    assert_eq!(export_weather(1i32..29i32).unwrap().rows.len(), 28usize);
+
+    for table in [
+        stock,
+        export_farm_events(&configuration, 1i32..113i32).unwrap(),
+        export_mines(&configuration, 1i32..3i32).unwrap(),
+        export_trash_cans(&configuration, 1i32..3i32).unwrap(),
+    ] {
+        for row in &table.rows {
+            assert_eq!(row.len(), table.columns.len());
+        }
+    }
+}
+
+#[test]
+fn known_tables() {
+    let configuration: Configuration = common::configuration(Platform::PC);
+
+    assert_eq!(
+        export_farm_events(&configuration, 1i32..32i32)
+            .unwrap()
+            .to_csv(),
+        "date,day,event,requirement\n\
+        \"Saturday Spring 13, Year 1\",13,Meteorite,Needs a free 2x2 space on the farm.\n\
+        \"Wednesday Summer 3, Year 1\",31,Earthquake,Opens the way to the railroad.\n"
+    );
+    assert_eq!(
+        export_geodes(&configuration, 1i32..2i32).unwrap().to_csv(),
+        "geodes_cracked,geode_type,id,item,quantity\n\
+        1,Geode,86,Earth Crystal,1\n\
+        1,Frozen Geode,84,Frozen Tear,1\n\
+        1,Magma Geode,82,Fire Quartz,1\n\
+        1,Omni Geode,84,Frozen Tear,1\n\
+        1,Artifact Trove,100,Chipped Amphora,1\n\
+        1,Golden Coconut,386,Iridium Ore,5\n"
+    );
+}
+
+#[test]
+fn filtered_stock() {
+    let configuration: Configuration = common::configuration(Platform::PC);
+    // Only the first two days selling a Coffee Bean, and nothing else they sell.
+    let table: ExportTable = export_stock_matching::<TravelingCartPredictor>(
+        "Traveling Cart",
+        &configuration,
+        1i32..1121i32,
+        2usize,
+        |stock_item: &StockItem| stock_item.item.name(stock_item.id).contains("Coffee"),
+    )
+    .unwrap();
+    assert_eq!(
+        table.to_csv(),
+        "date,day,id,item,price,quantity\n\
+        \"Friday Spring 5, Year 1\",5,433,Coffee Bean,2500,1\n\
+        \"Sunday Spring 7, Year 1\",7,433,Coffee Bean,2500,1\n"
+    );
+}
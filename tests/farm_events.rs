@@ -0,0 +1,134 @@
+mod common;
+
+use stardew_predictor::configuration::{Configuration, Platform};
+use stardew_predictor::predictors::farm_events::{find_farm_events, get_farm_event, FarmEvent};
+use stardew_predictor::predictors::util::{season_number, year_number};
+
+#[test]
+fn earthquake() {
+    for platform in [Platform::PC, Platform::Switch] {
+        let configuration: Configuration = common::configuration(platform);
+        // Summer 3 of the first year only.
+        assert!(get_farm_event(&configuration, 31i32).unwrap() == Some(FarmEvent::Earthquake));
+        assert!(
+            find_farm_events(&configuration, 32i32..1120i32, "earthquake", 1usize)
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
+
+#[test]
+fn known_events() {
+    use FarmEvent::*;
+
+    let known: [(Platform, Vec<(i32, FarmEvent)>); 2usize] = [
+        (
+            Platform::PC,
+            vec![
+                (13i32, Meteorite),
+                (31i32, Earthquake),
+                (45i32, Witch),
+                (61i32, Fairy),
+                (80i32, StoneOwl),
+                (116i32, Meteorite),
+                (120i32, Witch),
+                (128i32, Fairy),
+                (195i32, Fairy),
+                (212i32, Witch),
+                (219i32, Meteorite),
+                (222i32, StrangeCapsule),
+            ],
+        ),
+        (
+            Platform::Switch,
+            vec![
+                (31i32, Earthquake),
+                (68i32, StoneOwl),
+                (74i32, Meteorite),
+                (85i32, Witch),
+                (129i32, StoneOwl),
+                (144i32, Fairy),
+                (157i32, Meteorite),
+                (185i32, Fairy),
+                (203i32, Meteorite),
+                (216i32, Witch),
+            ],
+        ),
+    ];
+
+    for (platform, known) in known {
+        let configuration: Configuration = common::configuration(platform);
+        assert!(find_farm_events(&configuration, 1i32..225i32, "", usize::MAX).unwrap() == known);
+    }
+}
+
+#[test]
+fn animal_births() {
+    let births = |platform: Platform| {
+        let configuration: Configuration = Configuration {
+            player_id: Some(-6789i64),
+            ..common::configuration(platform)
+        };
+        find_farm_events(&configuration, 1i32..16i32, "animal", usize::MAX)
+            .unwrap()
+            .into_iter()
+            .map(|(date, _)| date)
+            .collect::<Vec<i32>>()
+    };
+
+    assert_eq!(
+        births(Platform::PC),
+        vec![2i32, 4i32, 6i32, 8i32, 10i32, 12i32, 14i32, 15i32]
+    );
+    assert_eq!(
+        births(Platform::Switch),
+        vec![1i32, 4i32, 5i32, 6i32, 9i32, 10i32, 13i32, 14i32, 15i32]
+    );
+
+    // The player's event only happens on nights without a farm event.
+    let configuration: Configuration = Configuration {
+        player_id: Some(-6789i64),
+        ..common::configuration(Platform::PC)
+    };
+    assert!(get_farm_event(&configuration, 13i32).unwrap() == Some(FarmEvent::Meteorite));
+}
+
+#[test]
+fn event_conditions() {
+    for platform in [Platform::PC, Platform::Switch] {
+        for seed in 0i32..500i32 {
+            let configuration: Configuration = Configuration {
+                seed,
+                ..common::configuration(platform)
+            };
+            for (date, farm_event) in
+                find_farm_events(&configuration, 1i32..337i32, "", usize::MAX).unwrap()
+            {
+                match farm_event {
+                    FarmEvent::Fairy => assert_ne!(season_number(date), 3u8),
+                    FarmEvent::Witch => assert!(date > 20i32),
+                    FarmEvent::Meteorite => assert!(date > 5i32),
+                    FarmEvent::StrangeCapsule => assert!(year_number(date) > 0u32),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn filter() {
+    let configuration: Configuration = common::configuration(Platform::PC);
+    let found: Vec<(i32, FarmEvent)> =
+        find_farm_events(&configuration, 1i32..11201i32, "meteor", 4usize).unwrap();
+    assert!(
+        found
+            == vec![
+                (13i32, FarmEvent::Meteorite),
+                (116i32, FarmEvent::Meteorite),
+                (219i32, FarmEvent::Meteorite),
+                (312i32, FarmEvent::Meteorite),
+            ]
+    );
+}
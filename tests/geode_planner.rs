@@ -0,0 +1,167 @@
+mod common;
+
+use stardew_predictor::configuration::{Configuration, Platform};
+use stardew_predictor::predictors::geode_planner::{plan_geodes, GeodePlan, PlannedCrack};
+use stardew_predictor::predictors::geodes::{get_geodes, Geode, GeodeType, GEODE_TYPES};
+
+fn configuration(platform: Platform, geodes_cracked: u16) -> Configuration {
+    Configuration {
+        geodes_cracked: Some(geodes_cracked),
+        ..common::configuration(platform)
+    }
+}
+
+fn found(configuration: &Configuration, order: &[usize], wanted: &[String]) -> usize {
+    let mut found: Vec<&String> = Vec::new();
+    for (crack, geode_type) in order.iter().enumerate() {
+        let geode: Geode = get_geodes(
+            configuration,
+            configuration.geodes_cracked.unwrap() as i32 + crack as i32,
+        )
+        .unwrap()[*geode_type];
+        for name in wanted {
+            if geode.item.name_matches(geode.id, name) && !found.contains(&name) {
+                found.push(name);
+            }
+        }
+    }
+
+    found.len()
+}
+
+/// The most wanted items any order of `held` finds.
+fn best(configuration: &Configuration, held: [u16; 6usize], wanted: &[String]) -> usize {
+    fn orders(held: &mut [u16; 6usize], order: &mut Vec<usize>, out: &mut Vec<Vec<usize>>) {
+        if held.iter().all(|held: &u16| *held == 0u16) {
+            out.push(order.clone());
+        }
+        for geode_type in 0usize..6usize {
+            if held[geode_type] > 0u16 {
+                held[geode_type] -= 1u16;
+                order.push(geode_type);
+                orders(held, order, out);
+                order.pop();
+                held[geode_type] += 1u16;
+            }
+        }
+    }
+
+    let mut out: Vec<Vec<usize>> = Vec::new();
+    orders(&mut held.clone(), &mut Vec::new(), &mut out);
+    out.iter()
+        .map(|order: &Vec<usize>| found(configuration, order, wanted))
+        .max()
+        .unwrap()
+}
+
+#[test]
+fn matches_every_order() {
+    let held: [u16; 6usize] = [2u16, 1u16, 1u16, 2u16, 0u16, 0u16];
+    for platform in [Platform::PC, Platform::Switch] {
+        for geodes_cracked in [0u16, 100u16, 1000u16] {
+            let configuration: Configuration = configuration(platform, geodes_cracked);
+
+            // Want some of what the omni geodes could give.
+            let wanted: Vec<String> = (0i32..6i32)
+                .map(|crack: i32| {
+                    let geode: Geode =
+                        get_geodes(&configuration, geodes_cracked as i32 + crack).unwrap()[3usize];
+                    geode.item.name(geode.id)
+                })
+                .step_by(2usize)
+                .collect();
+
+            let plan: GeodePlan = plan_geodes(&configuration, held, &wanted).unwrap();
+            assert!(plan.optimal);
+            let order: Vec<usize> = plan
+                .cracks
+                .iter()
+                .map(|planned_crack: &PlannedCrack| {
+                    GEODE_TYPES
+                        .iter()
+                        .position(|geode_type| *geode_type == planned_crack.geode_type)
+                        .unwrap()
+                })
+                .collect();
+
+            for (geode_type, held) in held.iter().enumerate() {
+                assert_eq!(
+                    order
+                        .iter()
+                        .filter(|other: &&usize| **other == geode_type)
+                        .count(),
+                    *held as usize
+                );
+            }
+            assert_eq!(
+                found(&configuration, &order, &wanted),
+                best(&configuration, held, &wanted)
+            );
+        }
+    }
+}
+
+#[test]
+fn known_plan() {
+    // Cracks 1 and 2 give a Frozen Tear from an omni geode and Star Shards from a magma geode,
+    // while the other orders find at most one wanted item.
+    let wanted: Vec<String> = ["Frozen Tear", "Fire Quartz", "Star Shards"]
+        .iter()
+        .map(|name: &&str| name.to_string())
+        .collect();
+    let plan: Vec<(i32, GeodeType, String, bool)> = plan_geodes(
+        &configuration(Platform::PC, 0u16),
+        [1u16, 0u16, 1u16, 1u16, 0u16, 0u16],
+        &wanted,
+    )
+    .unwrap()
+    .cracks
+    .into_iter()
+    .map(|planned_crack: PlannedCrack| {
+        (
+            planned_crack.geodes_cracked,
+            planned_crack.geode_type,
+            planned_crack.geode.item.name(planned_crack.geode.id),
+            planned_crack.wanted,
+        )
+    })
+    .collect();
+
+    assert!(
+        plan == vec![
+            (0i32, GeodeType::Geode, "Copper Ore".to_string(), false),
+            (1i32, GeodeType::OmniGeode, "Frozen Tear".to_string(), true),
+            (2i32, GeodeType::MagmaGeode, "Star Shards".to_string(), true),
+        ]
+    );
+}
+
+#[test]
+fn too_many_ways() {
+    // The first 32 distinct items from every geode type, which all compete for 12 cracks.
+    let configuration: Configuration = configuration(Platform::PC, 0u16);
+    let mut wanted: Vec<String> = Vec::new();
+    for crack in 0i32..2000i32 {
+        for geode in get_geodes(&configuration, crack).unwrap() {
+            let name: String = geode.item.name(geode.id);
+            if wanted.len() < 32usize && !wanted.contains(&name) {
+                wanted.push(name);
+            }
+        }
+    }
+
+    let held: [u16; 6usize] = [2u16; 6usize];
+    let plan: GeodePlan = plan_geodes(&configuration, held, &wanted).unwrap();
+    assert!(!plan.optimal);
+    assert_eq!(plan.cracks.len(), 12usize);
+    assert!(plan
+        .cracks
+        .iter()
+        .all(|planned_crack: &PlannedCrack| planned_crack.wanted));
+}
+
+#[test]
+fn too_many_wanted() {
+    let wanted: Vec<String> = (0u8..33u8).map(|index: u8| index.to_string()).collect();
+    assert!(plan_geodes(&configuration(Platform::PC, 0u16), [1u16; 6usize], &wanted).is_err());
+}
@@ -0,0 +1,48 @@
+use stardew_predictor::codegen::{BIG_CRAFTABLES_INFORMATION, FURNITURE, HATS, OBJECT_INFORMATION};
+use stardew_predictor::predictors::util::{Item, ItemDetails};
+
+#[test]
+fn object_details() {
+    let parsnip: ItemDetails = Item::ObjectInformation(&OBJECT_INFORMATION[&24u16]).details(24u16);
+    assert_eq!(parsnip.display_name, "Parsnip");
+    assert_eq!(parsnip.category, "Vegetable");
+    assert_eq!(parsnip.price, Some(35u32));
+    assert_eq!(parsnip.edibility, Some(10i16));
+    assert_eq!(parsnip.energy(), Some(25i32));
+    assert_eq!(parsnip.health(), Some(11i32));
+
+    let prismatic_shard: ItemDetails =
+        Item::ObjectInformation(&OBJECT_INFORMATION[&74u16]).details(74u16);
+    assert_eq!(prismatic_shard.category, "Mineral");
+    assert_eq!(prismatic_shard.edibility, None);
+    assert_eq!(prismatic_shard.energy(), None);
+
+    let dwarf_scroll: ItemDetails =
+        Item::ObjectInformation(&OBJECT_INFORMATION[&96u16]).details(96u16);
+    assert_eq!(dwarf_scroll.category, "Artifact");
+}
+
+#[test]
+fn other_details() {
+    for (id, big_craftable_information) in BIG_CRAFTABLES_INFORMATION.entries() {
+        let details: ItemDetails =
+            Item::BigCraftablesInformation(big_craftable_information).details(*id);
+        assert_eq!(details.category, "Big Craftable");
+        assert!(details.description.is_some());
+    }
+
+    for (id, furniture) in FURNITURE.entries() {
+        let details: ItemDetails = Item::Furniture(furniture).details(*id);
+        assert!(details.category.starts_with("Furniture ("));
+        assert_eq!(details.price, Some(furniture.price));
+    }
+
+    let cowboy_hat: ItemDetails = Item::Hats(&HATS[&0u16]).details(0u16);
+    assert_eq!(cowboy_hat.display_name, "Cowboy Hat");
+    assert_eq!(cowboy_hat.category, "Hat");
+    assert_eq!(cowboy_hat.price, None);
+
+    let flooring: ItemDetails = Item::Wallpaper(true).details(3u16);
+    assert_eq!(flooring.display_name, "Flooring (3)");
+    assert_eq!(flooring.category, "Flooring");
+}
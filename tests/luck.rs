@@ -0,0 +1,53 @@
+mod common;
+
+use stardew_predictor::configuration::Platform;
+use stardew_predictor::predictors::luck::{get_daily_luck, SpiritsMood};
+
+fn lucks(platform: Platform, date: i32, steps_taken: std::ops::Range<u32>) -> Vec<f64> {
+    steps_taken
+        .map(|steps_taken: u32| {
+            get_daily_luck(&common::configuration(platform), date, steps_taken).unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn known_luck() {
+    // Spring 2, going to bed after 0 to 4 steps.
+    assert_eq!(
+        lucks(Platform::PC, 2i32, 0u32..5u32),
+        vec![-0.02f64, -0.045f64, -0.071f64, 0.054f64, 0.08f64]
+    );
+    assert_eq!(
+        lucks(Platform::Switch, 2i32, 0u32..5u32),
+        vec![0.08f64, -0.08f64, -0.03f64, 0.002f64, -0.057f64]
+    );
+}
+
+#[test]
+fn luck_range() {
+    // Rolled from -0.1 to 0.1 in steps of 0.001.
+    for platform in [Platform::PC, Platform::Switch] {
+        let lucks: Vec<f64> = lucks(platform, 30i32, 0u32..5000u32);
+        assert!(lucks
+            .iter()
+            .all(|luck: &f64| (-0.1f64..=0.1f64).contains(luck)));
+        assert!(lucks.contains(&-0.1f64) && lucks.contains(&0.1f64));
+    }
+}
+
+#[test]
+fn spirits_mood() {
+    for (luck, mood) in [
+        (0.08f64, SpiritsMood::VeryHappy),
+        (0.07f64, SpiritsMood::GoodHumor),
+        (0.021f64, SpiritsMood::GoodHumor),
+        (0.02f64, SpiritsMood::Neutral),
+        (0f64, SpiritsMood::CompletelyNeutral),
+        (-0.02f64, SpiritsMood::Neutral),
+        (-0.021f64, SpiritsMood::SomewhatAnnoyed),
+        (-0.071f64, SpiritsMood::VeryDispleased),
+    ] {
+        assert!(SpiritsMood::from_luck(luck) == mood, "{}", luck);
+    }
+}
@@ -0,0 +1,116 @@
+mod common;
+
+use stardew_predictor::configuration::{Configuration, Platform};
+use stardew_predictor::predictors::mines::{
+    get_elevator_level, get_mine_floor, get_mine_floors, Infestation, MineFloor, MINES_BOTTOM,
+};
+
+/// The infested, dark and mushroom levels on a date.
+fn modified_levels(platform: Platform, date: i32) -> (Vec<(u8, Infestation)>, Vec<u8>, Vec<u8>) {
+    let floors: Vec<MineFloor> =
+        get_mine_floors(&common::configuration(platform), date, 1u8..=MINES_BOTTOM).unwrap();
+
+    (
+        floors
+            .iter()
+            .filter_map(|floor: &MineFloor| Some((floor.level, floor.infestation?)))
+            .collect(),
+        floors
+            .iter()
+            .filter(|floor: &&MineFloor| floor.dark)
+            .map(|floor: &MineFloor| floor.level)
+            .collect(),
+        floors
+            .iter()
+            .filter(|floor: &&MineFloor| floor.mushroom)
+            .map(|floor: &MineFloor| floor.level)
+            .collect(),
+    )
+}
+
+#[test]
+fn known_floors() {
+    let (infested, dark, mushroom) = modified_levels(Platform::PC, 1i32);
+    assert!(
+        infested
+            == vec![
+                (18u8, Infestation::Monsters),
+                (51u8, Infestation::Slimes),
+                (88u8, Infestation::Monsters),
+            ]
+    );
+    assert_eq!(
+        dark,
+        vec![
+            6u8, 14u8, 19u8, 23u8, 27u8, 31u8, 39u8, 43u8, 47u8, 48u8, 52u8, 56u8, 64u8, 68u8,
+            72u8, 76u8, 84u8, 89u8, 93u8, 97u8, 101u8, 109u8, 113u8, 117u8, 118u8,
+        ]
+    );
+    assert!(mushroom.is_empty());
+    assert_eq!(modified_levels(Platform::PC, 2i32).2, vec![92u8]);
+
+    let (infested, dark, mushroom) = modified_levels(Platform::Switch, 1i32);
+    assert!(
+        infested
+            == vec![
+                (11u8, Infestation::Monsters),
+                (28u8, Infestation::Slimes),
+                (62u8, Infestation::Monsters),
+            ]
+    );
+    assert_eq!(
+        dark,
+        vec![
+            12u8, 13u8, 14u8, 29u8, 31u8, 32u8, 46u8, 47u8, 48u8, 49u8, 63u8, 64u8, 66u8, 67u8,
+            81u8, 82u8, 83u8, 84u8, 97u8, 98u8, 99u8, 101u8, 114u8, 116u8, 117u8, 118u8,
+        ]
+    );
+    assert!(mushroom.is_empty());
+}
+
+#[test]
+fn floor_conditions() {
+    for platform in [Platform::PC, Platform::Switch] {
+        let configuration: Configuration = common::configuration(platform);
+        for date in 1i32..113i32 {
+            for floor in get_mine_floors(&configuration, date, 1u8..=MINES_BOTTOM).unwrap() {
+                let floor: MineFloor = floor;
+                if floor.level.is_multiple_of(5u8) {
+                    assert!(floor.is_normal());
+                }
+                if floor.infestation.is_some() {
+                    let map_number: u8 = floor.level % 40u8;
+                    assert!(map_number > 5u8 && map_number < 30u8 && map_number != 19u8);
+                    assert!(!floor.dark && !floor.mushroom);
+                }
+                if floor.dark {
+                    assert!(floor.level > 2u8);
+                }
+                if floor.mushroom {
+                    assert!(floor.level > 80u8);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn out_of_range() {
+    let configuration: Configuration = common::configuration(Platform::PC);
+    assert!(get_mine_floor(&configuration, 1i32, 0u8).is_err());
+    assert!(get_mine_floor(&configuration, 1i32, MINES_BOTTOM + 1u8).is_err());
+}
+
+#[test]
+fn elevator() {
+    let configuration_deepest: Configuration = common::configuration(Platform::PC);
+    assert_eq!(get_elevator_level(&configuration_deepest, 4u8), 0u8);
+    assert_eq!(get_elevator_level(&configuration_deepest, 97u8), 95u8);
+
+    let configuration_shallow: Configuration = Configuration {
+        mine_level: Some(42u8),
+        ..common::configuration(Platform::PC)
+    };
+    assert_eq!(get_elevator_level(&configuration_shallow, 37u8), 35u8);
+    assert_eq!(get_elevator_level(&configuration_shallow, 97u8), 40u8);
+}
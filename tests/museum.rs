@@ -0,0 +1,122 @@
+mod common;
+
+use stardew_predictor::codegen::OBJECT_INFORMATION;
+use stardew_predictor::configuration::{Configuration, Platform};
+use stardew_predictor::predictors::geodes::get_geodes;
+use stardew_predictor::predictors::museum::{get_museum_items, is_museum_item, is_still_needed};
+use stardew_predictor::predictors::traveling_cart::TravelingCartPredictor;
+use stardew_predictor::predictors::util::{get_stock_days, Item};
+use stardew_predictor::save::museum_from_save;
+
+#[test]
+fn museum_items() {
+    let museum_items: Vec<u16> = get_museum_items();
+    assert_eq!(museum_items.len(), 95usize);
+
+    let artifacts: usize = museum_items
+        .iter()
+        .filter(|id: &&u16| {
+            OBJECT_INFORMATION[*id]
+                .type_and_category
+                .starts_with("Arch")
+        })
+        .count();
+    assert_eq!(artifacts, 42usize);
+
+    // Prismatic Shard, Dwarf Scroll I and Parsnip.
+    assert!(museum_items.contains(&74u16));
+    assert!(museum_items.contains(&96u16));
+    assert!(!is_museum_item(&OBJECT_INFORMATION[&24u16]));
+}
+
+#[test]
+fn still_needed() {
+    let prismatic_shard: Item = Item::ObjectInformation(&OBJECT_INFORMATION[&74u16]);
+    let parsnip: Item = Item::ObjectInformation(&OBJECT_INFORMATION[&24u16]);
+
+    assert!(is_still_needed(&prismatic_shard, 74u16, &[]));
+    assert!(!is_still_needed(&prismatic_shard, 74u16, &[74u16]));
+    assert!(!is_still_needed(&parsnip, 24u16, &[]));
+}
+
+#[test]
+fn geodes_give_museum_items() {
+    // The Earth Crystal, Frozen Tear, Fire Quartz, Frozen Tear and Chipped Amphora, but not the
+    // Iridium Ore from the golden coconut.
+    let needed = |donated: &[u16]| -> Vec<u16> {
+        get_geodes(&common::configuration(Platform::PC), 1i32)
+            .unwrap()
+            .iter()
+            .filter(|geode| is_still_needed(&geode.item, geode.id, donated))
+            .map(|geode| geode.id)
+            .collect()
+    };
+
+    assert_eq!(needed(&[]), vec![86u16, 84u16, 82u16, 84u16, 100u16]);
+    assert_eq!(needed(&[84u16, 100u16]), vec![86u16, 82u16]);
+}
+
+#[test]
+fn traveling_cart_never_sells_museum_items() {
+    for platform in [Platform::PC, Platform::Switch] {
+        let configuration: Configuration = common::configuration(platform);
+        for stock_day in
+            get_stock_days::<TravelingCartPredictor>(&configuration, 1i32..337i32).unwrap()
+        {
+            for stock_item in stock_day.stock_items {
+                assert!(!is_still_needed(&stock_item.item, stock_item.id, &[]));
+            }
+        }
+    }
+}
+
+#[test]
+fn museum_from_save_1_5() {
+    let save: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<SaveGame xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xmlns:xsd="http://www.w3.org/2001/XMLSchema">
+  <locations>
+    <GameLocation xsi:type="Farm" />
+    <GameLocation xsi:type="LibraryMuseum">
+      <museumPieces>
+        <item>
+          <key><Vector2><X>26</X><Y>5</Y></Vector2></key>
+          <value><int>96</int></value>
+        </item>
+        <item>
+          <key><Vector2><X>27</X><Y>5</Y></Vector2></key>
+          <value><int>74</int></value>
+        </item>
+      </museumPieces>
+    </GameLocation>
+  </locations>
+</SaveGame>"#;
+
+    assert_eq!(museum_from_save(save).unwrap(), vec![74u16, 96u16]);
+}
+
+#[test]
+fn museum_from_save_1_6() {
+    let save: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<SaveGame xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xmlns:xsd="http://www.w3.org/2001/XMLSchema">
+  <locations>
+    <GameLocation xsi:type="LibraryMuseum">
+      <museumPieces>
+        <item>
+          <key><Vector2><X>26</X><Y>5</Y></Vector2></key>
+          <value><string>(O)60</string></value>
+        </item>
+      </museumPieces>
+    </GameLocation>
+  </locations>
+</SaveGame>"#;
+
+    assert_eq!(museum_from_save(save).unwrap(), vec![60u16]);
+}
+
+#[test]
+fn museum_from_save_without_museum() {
+    let save: &str = r#"<SaveGame><locations /></SaveGame>"#;
+
+    assert!(museum_from_save(save).is_err());
+    assert!(museum_from_save("<SaveGameInfo />").is_err());
+}
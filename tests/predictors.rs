@@ -0,0 +1,287 @@
+use std::fmt::Write;
+use std::fs;
+use std::path::PathBuf;
+
+mod common;
+
+use stardew_predictor::configuration::{Configuration, Platform};
+use stardew_predictor::predictors::farm_events::get_farm_event;
+use stardew_predictor::predictors::geodes::{get_geodes, GEODE_TYPES};
+use stardew_predictor::predictors::joja::JojaPredictor;
+use stardew_predictor::predictors::krobus::KrobusPredictor;
+use stardew_predictor::predictors::luck::get_daily_luck;
+use stardew_predictor::predictors::mines::{get_mine_floors, MINES_BOTTOM};
+use stardew_predictor::predictors::pierre::PierrePredictor;
+use stardew_predictor::predictors::saloon::SaloonPredictor;
+use stardew_predictor::predictors::sandy::SandyPredictor;
+use stardew_predictor::predictors::trash_cans::{get_trash_cans_loot, TrashFind};
+use stardew_predictor::predictors::traveling_cart::TravelingCartPredictor;
+use stardew_predictor::predictors::util::{get_stock_days, StockPredictor};
+
+const DAYS: i32 = 28i32;
+const GEODES: i32 = 20i32;
+const STEPS: u32 = 20u32;
+// Nightly events are rare, so these cover ten years.
+const NIGHTS: i32 = 1120i32;
+const MINE_DAYS: i32 = 2i32;
+
+fn write_stock<T: StockPredictor>(out: &mut String, configuration: &Configuration) {
+    let date: i32 = configuration.date.unwrap_or(1i32);
+    for stock_day in get_stock_days::<T>(configuration, date..date + DAYS).unwrap() {
+        for stock_item in stock_day.stock_items {
+            writeln!(
+                out,
+                "{}\t{}\t{}\t{}\t{}",
+                stock_day.date,
+                stock_item.id,
+                stock_item.item.name(stock_item.id),
+                stock_item.price,
+                stock_item.quantity,
+            )
+            .unwrap();
+        }
+    }
+}
+
+fn write_geodes(out: &mut String, configuration: &Configuration) {
+    let geodes_cracked: i32 = configuration.geodes_cracked.unwrap_or(0u16) as i32;
+    for geodes_cracked in geodes_cracked..geodes_cracked + GEODES {
+        for (geode_type, geode) in GEODE_TYPES
+            .iter()
+            .zip(get_geodes(configuration, geodes_cracked).unwrap())
+        {
+            writeln!(
+                out,
+                "{}\t{}\t{}\t{}\t{}",
+                geode_type,
+                geodes_cracked,
+                geode.id,
+                geode.item.name(geode.id),
+                geode.quantity,
+            )
+            .unwrap();
+        }
+    }
+}
+
+fn write_luck(out: &mut String, configuration: &Configuration) {
+    let date: i32 = configuration.date.unwrap_or(1i32) + 1i32;
+    let steps_taken: u32 = configuration.steps_taken.unwrap_or(0u32);
+    for steps_taken in steps_taken..steps_taken + STEPS {
+        writeln!(
+            out,
+            "{}\t{}\t{}",
+            date,
+            steps_taken,
+            get_daily_luck(configuration, date, steps_taken).unwrap(),
+        )
+        .unwrap();
+    }
+}
+
+fn write_farm_events(out: &mut String, configuration: &Configuration) {
+    let date: i32 = configuration.date.unwrap_or(1i32);
+    for date in date..date + NIGHTS {
+        if let Some(farm_event) = get_farm_event(configuration, date).unwrap() {
+            writeln!(out, "{}\t{}", date, farm_event).unwrap();
+        }
+    }
+}
+
+fn write_mines(out: &mut String, configuration: &Configuration) {
+    let date: i32 = configuration.date.unwrap_or(1i32);
+    for date in date..date + MINE_DAYS {
+        for floor in get_mine_floors(configuration, date, 1u8..=MINES_BOTTOM).unwrap() {
+            if !floor.is_normal() {
+                writeln!(
+                    out,
+                    "{}\t{}\t{}\t{}\t{}",
+                    date,
+                    floor.level,
+                    floor
+                        .infestation
+                        .map(|infestation| infestation.to_string())
+                        .unwrap_or_default(),
+                    floor.dark,
+                    floor.mushroom,
+                )
+                .unwrap();
+            }
+        }
+    }
+}
+
+fn write_trash_cans(out: &mut String, configuration: &Configuration) {
+    let format_find = |find: Option<TrashFind>| match find {
+        Some(find) => format!("{}\t{:?}", find.trash_item.name(), find.luck),
+        None => "\t".to_string(),
+    };
+
+    let date: i32 = configuration.date.unwrap_or(1i32);
+    for date in date..date + DAYS {
+        for trash_can_loot in get_trash_cans_loot(configuration, date).unwrap() {
+            if trash_can_loot.find.is_some() {
+                writeln!(
+                    out,
+                    "{}\t{}\t{}\t{}",
+                    date,
+                    trash_can_loot.trash_can,
+                    format_find(trash_can_loot.find),
+                    format_find(trash_can_loot.special),
+                )
+                .unwrap();
+            }
+        }
+    }
+}
+
+/// Compares against `tests/fixtures/<predictor>/<name>.tsv`. The fixtures were recorded from the
+/// predictors rather than checked in game, so they catch changes rather than prove correctness.
+/// Set `BLESS_FIXTURES` to rewrite them after an intended change, and check the diff.
+fn check_fixture(
+    predictor: &str,
+    name: &str,
+    configuration: &Configuration,
+    write: fn(&mut String, &Configuration),
+) {
+    let mut actual: String = String::new();
+    write(&mut actual, configuration);
+
+    let path: PathBuf = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join(predictor)
+        .join(format!("{}.tsv", name));
+    if std::env::var_os("BLESS_FIXTURES").is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, &actual).unwrap();
+        return;
+    }
+
+    let expected: String = fs::read_to_string(&path).unwrap();
+    for (line, (actual, expected)) in actual.lines().zip(expected.lines()).enumerate() {
+        assert_eq!(
+            actual,
+            expected,
+            "{}/{} differs at line {}",
+            predictor,
+            name,
+            line + 1usize
+        );
+    }
+    assert_eq!(
+        actual.lines().count(),
+        expected.lines().count(),
+        "{}/{} length differs",
+        predictor,
+        name
+    );
+}
+
+fn year_one(platform: Platform) -> Configuration {
+    common::configuration(platform)
+}
+
+// Winter of year two, which includes the night market, with most optional parameters set.
+fn year_two(platform: Platform) -> Configuration {
+    Configuration {
+        seed: -98765i32,
+        date: Some(197i32),
+        geodes_cracked: Some(100u16),
+        mine_level: Some(40u8),
+        qis_crop: Some(true),
+        golden_helmet: Some(false),
+        year_one_completable: Some(true),
+        steps_taken: Some(4321u32),
+        ..common::configuration(platform)
+    }
+}
+
+fn check_fixtures(predictor: &str, write: fn(&mut String, &Configuration)) {
+    for platform in [Platform::PC, Platform::Switch] {
+        let platform_name: String = platform.to_string().to_lowercase();
+        check_fixture(
+            predictor,
+            &format!("{}_year_one", platform_name),
+            &year_one(platform),
+            write,
+        );
+        check_fixture(
+            predictor,
+            &format!("{}_year_two", platform_name),
+            &year_two(platform),
+            write,
+        );
+    }
+}
+
+#[test]
+fn traveling_cart() {
+    check_fixtures("traveling_cart", write_stock::<TravelingCartPredictor>);
+
+    // The guarantee on Spring 7 shifts the rest of that day's stock.
+    for platform in [Platform::PC, Platform::Switch] {
+        check_fixture(
+            "traveling_cart",
+            &format!(
+                "{}_year_one_completable",
+                platform.to_string().to_lowercase()
+            ),
+            &Configuration {
+                year_one_completable: Some(true),
+                ..year_one(platform)
+            },
+            write_stock::<TravelingCartPredictor>,
+        );
+    }
+}
+
+#[test]
+fn krobus() {
+    check_fixtures("krobus", write_stock::<KrobusPredictor>);
+}
+
+#[test]
+fn sandy() {
+    check_fixtures("sandy", write_stock::<SandyPredictor>);
+}
+
+#[test]
+fn pierre() {
+    check_fixtures("pierre", write_stock::<PierrePredictor>);
+}
+
+#[test]
+fn joja() {
+    check_fixtures("joja", write_stock::<JojaPredictor>);
+}
+
+#[test]
+fn saloon() {
+    check_fixtures("saloon", write_stock::<SaloonPredictor>);
+}
+
+#[test]
+fn geodes() {
+    check_fixtures("geodes", write_geodes);
+}
+
+#[test]
+fn luck() {
+    check_fixtures("luck", write_luck);
+}
+
+#[test]
+fn farm_events() {
+    check_fixtures("farm_events", write_farm_events);
+}
+
+#[test]
+fn mines() {
+    check_fixtures("mines", write_mines);
+}
+
+#[test]
+fn trash_cans() {
+    check_fixtures("trash_cans", write_trash_cans);
+}
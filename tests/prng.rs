@@ -0,0 +1,228 @@
+use stardew_predictor::configuration::Platform;
+use stardew_predictor::prng::{
+    create_random, create_random_seed, xxhash32, Jkiss, MsCorLibRandom, Prng,
+};
+
+fn gen_ranges<P: Prng>(seed: i32, range: std::ops::Range<i32>, count: usize) -> Vec<i32> {
+    let mut prng: P = P::from_seed(seed).unwrap();
+    (0usize..count)
+        .map(|_| prng.gen_range(range.clone()).unwrap())
+        .collect()
+}
+
+fn gen_floats<P: Prng>(seed: i32, count: usize) -> Vec<f64> {
+    let mut prng: P = P::from_seed(seed).unwrap();
+    (0usize..count).map(|_| prng.gen_float().unwrap()).collect()
+}
+
+// `new System.Random(0)` on .NET, the first five values of `Next()`.
+#[test]
+fn ms_cor_lib_random_matches_dot_net_next() {
+    assert_eq!(
+        gen_ranges::<MsCorLibRandom>(0i32, 0i32..i32::MAX, 5usize),
+        vec![
+            1559595546i32,
+            1755192844i32,
+            1649316166i32,
+            1198642031i32,
+            442452829i32
+        ]
+    );
+}
+
+// `new System.Random(0)` on .NET, the first three values of `NextDouble()`.
+#[test]
+fn ms_cor_lib_random_matches_dot_net_next_double() {
+    assert_eq!(
+        gen_floats::<MsCorLibRandom>(0i32, 3usize),
+        vec![
+            0.7262432699679598f64,
+            0.8173253595909687f64,
+            0.7680226893946634f64
+        ]
+    );
+}
+
+#[test]
+fn ms_cor_lib_random_matches_dot_net_small_range() {
+    assert_eq!(
+        gen_ranges::<MsCorLibRandom>(12345i32, 0i32..100i32, 8usize),
+        vec![6i32, 7i32, 77i32, 51i32, 79i32, 82i32, 16i32, 73i32]
+    );
+}
+
+// .NET seeds with the absolute value, and `i32::MIN` as `i32::MAX`.
+#[test]
+fn ms_cor_lib_random_seeds_with_absolute_value() {
+    assert_eq!(
+        gen_ranges::<MsCorLibRandom>(-12345i32, 0i32..100i32, 8usize),
+        gen_ranges::<MsCorLibRandom>(12345i32, 0i32..100i32, 8usize)
+    );
+    assert_eq!(
+        gen_ranges::<MsCorLibRandom>(i32::MIN, 0i32..100i32, 8usize),
+        vec![72i32, 81i32, 76i32, 55i32, 20i32, 55i32, 90i32, 44i32]
+    );
+    assert_eq!(
+        gen_ranges::<MsCorLibRandom>(i32::MAX, 0i32..100i32, 8usize),
+        gen_ranges::<MsCorLibRandom>(i32::MIN, 0i32..100i32, 8usize)
+    );
+}
+
+// Ranges wider than `i32::MAX` take the `GetSampleForLargeRange` branch.
+#[test]
+fn ms_cor_lib_random_matches_dot_net_large_range() {
+    assert_eq!(
+        gen_ranges::<MsCorLibRandom>(12345i32, i32::MIN..i32::MAX, 6usize),
+        vec![
+            -143337953i32,
+            1663795457i32,
+            -1712597935i32,
+            -356393801i32,
+            558810387i32,
+            494509052i32
+        ]
+    );
+    assert_eq!(
+        gen_ranges::<MsCorLibRandom>(12345i32, (i32::MIN + 5i32)..i32::MAX, 4usize),
+        vec![-143337950i32, 1663795458i32, -1712597931i32, -356393798i32]
+    );
+}
+
+#[test]
+fn ms_cor_lib_random_rejects_empty_range() {
+    let mut prng: MsCorLibRandom = MsCorLibRandom::from_seed(0i32).unwrap();
+    assert!(prng.gen_range(5i32..5i32).is_err());
+}
+
+// These are not recorded from a runtime, so they only catch changes to `Jkiss`, not prove it
+// bit-exact. Mono's JKISS `System.Random` (mono/mono commit 8d3c6d44) assigns the seed to `x`
+// directly, while the Switch's runtime seeds `x` through one step of the LCG, so stock Mono's
+// output wouldn't match either. They come from a separate port of that seeding, and should be
+// replaced with values recorded on the Switch.
+#[test]
+fn jkiss_matches_recorded_range() {
+    assert_eq!(
+        gen_ranges::<Jkiss>(0i32, 0i32..100i32, 8usize),
+        vec![15i32, 47i32, 10i32, 66i32, 24i32, 40i32, 26i32, 92i32]
+    );
+    assert_eq!(
+        gen_ranges::<Jkiss>(12345i32, 0i32..100i32, 8usize),
+        vec![48i32, 0i32, 11i32, 91i32, 25i32, 65i32, 91i32, 81i32]
+    );
+    assert_eq!(
+        gen_ranges::<Jkiss>(i32::MIN, 0i32..100i32, 8usize),
+        vec![67i32, 95i32, 62i32, 18i32, 72i32, 88i32, 78i32, 44i32]
+    );
+}
+
+#[test]
+fn jkiss_matches_recorded_float() {
+    assert_eq!(
+        gen_floats::<Jkiss>(12345i32, 3usize),
+        vec![
+            0.14648813295504115f64,
+            0.24052603749759838f64,
+            0.8034284728848048f64
+        ]
+    );
+}
+
+#[test]
+fn jkiss_matches_recorded_large_range() {
+    assert_eq!(
+        gen_ranges::<Jkiss>(12345i32, i32::MIN..i32::MAX, 4usize),
+        vec![-1518321900i32, -497410648i32, -1114432137i32, -954468557i32]
+    );
+}
+
+// A difference of 1 returns the start without advancing the generator.
+#[test]
+fn jkiss_difference_of_one_does_not_advance() {
+    let mut prng: Jkiss = Jkiss::from_seed(12345i32).unwrap();
+    for _ in 0usize..3usize {
+        assert_eq!(prng.gen_range(7i32..8i32).unwrap(), 7i32);
+    }
+    assert_eq!(prng.gen_range(0i32..100i32).unwrap(), 48i32);
+    assert_eq!(prng.gen_range(0i32..100i32).unwrap(), 0i32);
+}
+
+#[test]
+fn jkiss_rejects_empty_range() {
+    let mut prng: Jkiss = Jkiss::from_seed(0i32).unwrap();
+    assert!(prng.gen_range(5i32..5i32).is_err());
+}
+
+#[test]
+fn xxhash32_matches_reference_vectors() {
+    assert_eq!(xxhash32(b""), 0x02cc5d05u32);
+    assert_eq!(xxhash32(b"a"), 0x550d7456u32);
+    assert_eq!(xxhash32(b"abc"), 0x32d153ffu32);
+    assert_eq!(
+        xxhash32(b"Nobody inspects the spammish repetition"),
+        0xe2293b2fu32
+    );
+}
+
+#[test]
+fn create_random_seed_hashes_five_little_endian_values() {
+    let mut data: Vec<u8> = Vec::new();
+    for value in [12345i32, -7i32, 1i32, 0i32, 0i32] {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+
+    assert_eq!(
+        create_random_seed(&[12345f64, -7f64, 1f64], false).unwrap(),
+        xxhash32(&data) as i32
+    );
+}
+
+#[test]
+fn create_random_seed_reduces_values_modulo_i32_max() {
+    assert_eq!(
+        create_random_seed(&[i32::MAX as f64 + 3f64, 5f64], false).unwrap(),
+        create_random_seed(&[3f64, 5f64], false).unwrap()
+    );
+    assert_eq!(
+        create_random_seed(&[-(i32::MAX as f64) - 3f64], false).unwrap(),
+        create_random_seed(&[-3f64], false).unwrap()
+    );
+}
+
+#[test]
+fn create_random_seed_sums_in_legacy_mode() {
+    assert_eq!(create_random_seed(&[1f64, 2f64, 3f64], true).unwrap(), 6i32);
+    assert_eq!(
+        create_random_seed(&[(i32::MAX - 2i32) as f64, 3f64], true).unwrap(),
+        1i32
+    );
+    assert_eq!(
+        create_random_seed(&[12.75f64, 0.5f64], true).unwrap(),
+        13i32
+    );
+}
+
+#[test]
+fn create_random_seed_rejects_more_than_five_values() {
+    assert!(create_random_seed(&[0f64; 6usize], false).is_err());
+}
+
+#[test]
+fn create_random_uses_the_platform_prng() {
+    let seed: i32 = create_random_seed(&[12345f64, 7f64], false).unwrap();
+
+    let mut pc: Box<dyn Prng> = create_random(Platform::PC, &[12345f64, 7f64], false).unwrap();
+    let mut ms_cor_lib_random: MsCorLibRandom = MsCorLibRandom::from_seed(seed).unwrap();
+    let mut switch: Box<dyn Prng> =
+        create_random(Platform::Switch, &[12345f64, 7f64], false).unwrap();
+    let mut jkiss: Jkiss = Jkiss::from_seed(seed).unwrap();
+    for _ in 0u8..10u8 {
+        assert_eq!(
+            pc.gen_range(0i32..1000i32).unwrap(),
+            ms_cor_lib_random.gen_range(0i32..1000i32).unwrap()
+        );
+        assert_eq!(
+            switch.gen_range(0i32..1000i32).unwrap(),
+            jkiss.gen_range(0i32..1000i32).unwrap()
+        );
+    }
+}
@@ -0,0 +1,36 @@
+mod common;
+
+use stardew_predictor::configuration::{Configuration, Platform};
+use stardew_predictor::predictors::saloon::SaloonPredictor;
+use stardew_predictor::predictors::util::{StockItem, StockPredictor};
+
+fn dish_of_the_day(platform: Platform, date: i32) -> (u16, u8) {
+    let configuration: Configuration = Configuration {
+        steps_taken: Some(0u32),
+        ..common::configuration(platform)
+    };
+    let stock_items: Vec<StockItem> = SaloonPredictor::get_stock(&configuration, date)
+        .unwrap()
+        .unwrap();
+    assert_eq!(stock_items.len(), 1usize);
+
+    (stock_items[0usize].id, stock_items[0usize].quantity)
+}
+
+#[test]
+fn pc() {
+    // Vegetable Medley, Fish Taco.
+    assert_eq!(dish_of_the_day(Platform::PC, 1i32), (200u16, 1u8));
+    assert_eq!(dish_of_the_day(Platform::PC, 2i32), (213u16, 1u8));
+    // Rolls Pizza (206) first, which is always sold, so is re-rolled to Cookie.
+    assert_eq!(dish_of_the_day(Platform::PC, 17i32), (223u16, 3u8));
+}
+
+#[test]
+fn switch() {
+    // Strange Bun, Tom Kha Soup with the larger stack.
+    assert_eq!(dish_of_the_day(Platform::Switch, 1i32), (203u16, 1u8));
+    assert_eq!(dish_of_the_day(Platform::Switch, 2i32), (218u16, 12u8));
+    // Rolls Spaghetti (224) first, which is always sold, so is re-rolled to Glazed Yams.
+    assert_eq!(dish_of_the_day(Platform::Switch, 4i32), (208u16, 3u8));
+}
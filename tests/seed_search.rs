@@ -0,0 +1,81 @@
+mod common;
+
+use stardew_predictor::configuration::{Configuration, Platform};
+use stardew_predictor::predictors::traveling_cart::TravelingCartPredictor;
+use stardew_predictor::predictors::util::{StockItem, StockPredictor};
+use stardew_predictor::seed_search::{scan_seeds, search_seeds, seed_matches, SeedPredicate};
+
+#[test]
+fn scan_matches_search() {
+    // Negative seeds, with a geode count that underflows when added to half the seed.
+    let predicates: Vec<SeedPredicate> = vec!["geode:geode:-2147483648:stone"
+        .parse::<SeedPredicate>()
+        .unwrap()];
+    for platform in [Platform::PC, Platform::Switch] {
+        let configuration: Configuration = common::configuration(platform);
+        let seeds = i32::MIN..=i32::MIN + 1000i32;
+        let searched: Vec<i32> = search_seeds(&configuration, seeds.clone(), &predicates)
+            .collect::<Result<Vec<i32>, _>>()
+            .unwrap();
+        assert!(!searched.is_empty());
+        assert_eq!(
+            scan_seeds(&configuration, seeds, &predicates, 4usize, usize::MAX).unwrap(),
+            searched
+        );
+    }
+}
+
+#[test]
+fn scan_respects_limit() {
+    let predicates: Vec<SeedPredicate> =
+        vec!["cart:coffee bean:29".parse::<SeedPredicate>().unwrap()];
+    for platform in [Platform::PC, Platform::Switch] {
+        let configuration: Configuration = common::configuration(platform);
+        let found: Vec<i32> =
+            scan_seeds(&configuration, 0i32..=i32::MAX, &predicates, 4usize, 3usize).unwrap();
+        assert_eq!(found.len(), 3usize);
+        for seed in found {
+            let configuration: Configuration = Configuration {
+                seed,
+                ..configuration.clone()
+            };
+            assert!(seed_matches(&configuration, &predicates).unwrap());
+        }
+    }
+}
+
+#[test]
+fn scan_matches_cart_stock() {
+    let predicates: Vec<SeedPredicate> =
+        vec!["cart:coffee bean:29".parse::<SeedPredicate>().unwrap()];
+    for platform in [Platform::PC, Platform::Switch] {
+        let configuration: Configuration = Configuration {
+            year_one_completable: Some(true),
+            ..common::configuration(platform)
+        };
+        let seeds = -200i32..=200i32;
+        let stocked: Vec<i32> = seeds
+            .clone()
+            .filter(|&seed: &i32| {
+                let configuration: Configuration = Configuration {
+                    seed,
+                    ..configuration.clone()
+                };
+                (1i32..29i32).any(|date: i32| {
+                    TravelingCartPredictor::get_stock(&configuration, date)
+                        .unwrap()
+                        .unwrap_or_default()
+                        .iter()
+                        .any(|stock_item: &StockItem| {
+                            stock_item.item.name_matches(stock_item.id, "Coffee Bean")
+                        })
+                })
+            })
+            .collect();
+        assert!(!stocked.is_empty() && stocked.len() < 401usize);
+        assert_eq!(
+            scan_seeds(&configuration, seeds, &predicates, 4usize, usize::MAX).unwrap(),
+            stocked
+        );
+    }
+}
@@ -0,0 +1,111 @@
+mod common;
+
+use stardew_predictor::configuration::{Configuration, Platform};
+use stardew_predictor::predictors::trash_cans::{
+    get_trash_cans_loot, TrashCan, TrashCanLoot, TrashFind, TrashItem,
+};
+
+fn describe(find: Option<TrashFind>) -> String {
+    match find {
+        Some(find) => format!("{} {:?}", find.trash_item.name(), find.luck),
+        None => "-".to_string(),
+    }
+}
+
+/// The cans with a find on a date, with what's found and the luck needed.
+fn finds(platform: Platform, date: i32) -> Vec<String> {
+    get_trash_cans_loot(&common::configuration(platform), date)
+        .unwrap()
+        .into_iter()
+        .filter(|trash_can_loot: &TrashCanLoot| trash_can_loot.find.is_some())
+        .map(|trash_can_loot: TrashCanLoot| {
+            format!(
+                "{}: {}, {}",
+                trash_can_loot.trash_can,
+                describe(trash_can_loot.find),
+                describe(trash_can_loot.special)
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn known_finds() {
+    assert_eq!(
+        finds(Platform::PC, 1i32),
+        vec![
+            "Evelyn and George: Joja Cola None, Cookie Some(-0.015108158539565375)",
+            "JojaMart: Acorn Some(-0.07434785667543667), -",
+        ]
+    );
+    assert_eq!(
+        finds(Platform::PC, 5i32),
+        vec![
+            "Emily and Haley: Joja Cola Some(0.02072248869609203), -",
+            "Lewis: Maple Seed None, -",
+            "Blacksmith: Broken Glasses Some(0.03586258303181389), -",
+            "Saloon: Broken CD None, Rhubarb Pie Some(-0.0316470341904308)",
+        ]
+    );
+    assert_eq!(
+        finds(Platform::Switch, 1i32),
+        vec![
+            "Jodi and Kent: Acorn Some(0.06311245784022518), -",
+            "Lewis: Bread Some(0.029743978616250055), -",
+            "Museum: Soggy Newspaper Some(0.06946663918534007), Geode Some(0.06946663918534007)",
+            "Evelyn and George: Joja Cola None, -",
+        ]
+    );
+}
+
+fn is_garbage_hat(trash_can_loot: &TrashCanLoot) -> bool {
+    matches!(
+        trash_can_loot.find.map(|find| find.trash_item),
+        Some(TrashItem::Item { id: 66u16, item: _ })
+    )
+}
+
+#[test]
+fn specials() {
+    for platform in [Platform::PC, Platform::Switch] {
+        let configuration: Configuration = common::configuration(platform);
+        for date in 1i32..1121i32 {
+            for trash_can_loot in get_trash_cans_loot(&configuration, date).unwrap() {
+                if let Some(special) = trash_can_loot.special {
+                    assert!(!matches!(
+                        trash_can_loot.trash_can,
+                        TrashCan::JodiAndKent | TrashCan::EmilyAndHaley | TrashCan::Lewis
+                    ));
+                    let find_luck: f64 = trash_can_loot.find.unwrap().luck.unwrap_or(-1f64);
+                    assert!(special.luck.unwrap_or(-1f64) >= find_luck);
+                }
+                for find in [trash_can_loot.find, trash_can_loot.special]
+                    .into_iter()
+                    .flatten()
+                {
+                    if let Some(luck) = find.luck {
+                        assert!((-0.1f64..0.125f64).contains(&luck));
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn garbage_hat() {
+    let found = |trash_cans_checked: Option<u32>| -> usize {
+        let configuration: Configuration = Configuration {
+            trash_cans_checked,
+            ..common::configuration(Platform::PC)
+        };
+        (1i32..1121i32)
+            .flat_map(|date: i32| get_trash_cans_loot(&configuration, date).unwrap())
+            .filter(is_garbage_hat)
+            .count()
+    };
+
+    assert_eq!(found(Some(19u32)), 0usize);
+    assert!(found(Some(20u32)) > 0usize);
+    assert_eq!(found(None), found(Some(20u32)));
+}
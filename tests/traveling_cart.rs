@@ -0,0 +1,141 @@
+mod common;
+
+use stardew_predictor::configuration::{Configuration, Platform};
+use stardew_predictor::predictors::traveling_cart::{has_y1_guarantee, TravelingCartPredictor};
+use stardew_predictor::predictors::util::{StockItem, StockPredictor};
+use stardew_predictor::save::configuration_from_save;
+
+fn stock(configuration: &Configuration, date: i32) -> Vec<(u16, u32, u8)> {
+    TravelingCartPredictor::get_stock(configuration, date)
+        .unwrap()
+        .unwrap()
+        .iter()
+        .map(|stock_item: &StockItem| (stock_item.id, stock_item.price, stock_item.quantity))
+        .collect()
+}
+
+/// Checks Spring 7, the second visit, where the rolls for Red Cabbage Seeds shift the rest.
+fn check_y1_guarantee(
+    platform: Platform,
+    objects: &[(u16, u32, u8)],
+    without: &[(u16, u32, u8)],
+    with: &[(u16, u32, u8)],
+) {
+    let configuration: Configuration = common::configuration(platform);
+    assert_eq!(stock(&configuration, 7i32), [objects, without].concat());
+
+    let configuration: Configuration = Configuration {
+        year_one_completable: Some(true),
+        ..configuration
+    };
+    assert_eq!(stock(&configuration, 7i32), [objects, with].concat());
+}
+
+#[test]
+fn y1_guarantee_shifts_stock() {
+    // The furniture changes, and the Coffee Bean is no longer sold.
+    check_y1_guarantee(
+        Platform::PC,
+        &[
+            (591u16, 200u32, 5u8),
+            (766u16, 1000u32, 1u8),
+            (128u16, 800u32, 1u8),
+            (78u16, 700u32, 1u8),
+            (129u16, 100u32, 1u8),
+            (130u16, 300u32, 1u8),
+            (593u16, 360u32, 1u8),
+            (88u16, 400u32, 1u8),
+            (767u16, 800u32, 1u8),
+            (344u16, 640u32, 1u8),
+        ],
+        &[
+            (312u16, 750u32, 1u8),
+            (347u16, 1000u32, 1u8),
+            (433u16, 2500u32, 1u8),
+        ],
+        &[
+            (485u16, 800u32, 1u8),
+            (1557u16, 2500u32, 1u8),
+            (347u16, 1000u32, 1u8),
+        ],
+    );
+
+    // The furniture's price changes.
+    check_y1_guarantee(
+        Platform::Switch,
+        &[
+            (465u16, 100u32, 1u8),
+            (16u16, 250u32, 1u8),
+            (210u16, 360u32, 1u8),
+            (18u16, 900u32, 1u8),
+            (591u16, 900u32, 1u8),
+            (138u16, 800u32, 1u8),
+            (322u16, 900u32, 1u8),
+            (593u16, 450u32, 1u8),
+            (638u16, 700u32, 1u8),
+            (632u16, 4500u32, 1u8),
+        ],
+        &[(1148u16, 1250u32, 1u8), (347u16, 1000u32, 1u8)],
+        &[
+            (485u16, 500u32, 1u8),
+            (1148u16, 1000u32, 1u8),
+            (347u16, 1000u32, 1u8),
+        ],
+    );
+}
+
+#[test]
+fn y1_guarantee_visits() {
+    let mut configuration: Configuration = common::configuration(Platform::PC);
+    assert!(!has_y1_guarantee(&configuration, 7i32));
+
+    // From the start of the game, the second visit.
+    configuration.year_one_completable = Some(true);
+    assert!(!has_y1_guarantee(&configuration, 5i32));
+    assert!(has_y1_guarantee(&configuration, 7i32));
+    assert!(!has_y1_guarantee(&configuration, 12i32));
+
+    // From a save on Spring 8, the sixth visit.
+    configuration.date = Some(8i32);
+    configuration.visits_until_y1_guarantee = Some(4i32);
+    assert!(!has_y1_guarantee(&configuration, 19i32));
+    assert!(has_y1_guarantee(&configuration, 21i32));
+    assert!(!has_y1_guarantee(&configuration, 26i32));
+
+    // Once made, or with the option off.
+    configuration.visits_until_y1_guarantee = Some(-1i32);
+    assert!((1i32..113i32).all(|date: i32| !has_y1_guarantee(&configuration, date)));
+}
+
+#[test]
+fn y1_guarantee_from_save() {
+    let save = |visits_until_y1_guarantee: &str| {
+        format!(
+            "<SaveGame><player><UniqueMultiplayerID>-6789</UniqueMultiplayerID>\
+            <deepestMineLevel>0</deepestMineLevel><stats /></player>\
+            <currentSeason>spring</currentSeason><dayOfMonth>3</dayOfMonth><year>1</year>\
+            <uniqueIDForThisGame>12345</uniqueIDForThisGame>{}</SaveGame>",
+            visits_until_y1_guarantee
+        )
+    };
+
+    let configuration: Configuration = configuration_from_save(
+        &save("<visitsUntilY1Guarantee>2</visitsUntilY1Guarantee>"),
+        Platform::PC,
+    )
+    .unwrap();
+    assert_eq!(configuration.year_one_completable, Some(true));
+    assert_eq!(configuration.visits_until_y1_guarantee, Some(2i32));
+    assert!(has_y1_guarantee(&configuration, 7i32));
+
+    let configuration: Configuration = configuration_from_save(
+        &save("<visitsUntilY1Guarantee>-1</visitsUntilY1Guarantee>"),
+        Platform::PC,
+    )
+    .unwrap();
+    assert_eq!(configuration.year_one_completable, None);
+    assert!(!has_y1_guarantee(&configuration, 7i32));
+
+    let configuration: Configuration = configuration_from_save(&save(""), Platform::PC).unwrap();
+    assert_eq!(configuration.visits_until_y1_guarantee, None);
+}
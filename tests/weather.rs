@@ -0,0 +1,70 @@
+use stardew_predictor::predictors::weather::{get_weather_chances, is_festival, WeatherChances};
+
+fn chances(date: i32) -> WeatherChances {
+    get_weather_chances(date)
+}
+
+#[test]
+fn fixed_days() {
+    // Spring 1, Summer 1 of year two.
+    for date in [1i32, 141i32] {
+        assert_eq!(chances(date).sunny, 1f64);
+    }
+    // Spring 3.
+    assert_eq!(chances(3i32).rain, 1f64);
+    // Egg Festival and Feast of the Winter Star.
+    for date in [13i32, 109i32] {
+        assert!(is_festival(date));
+        assert_eq!(chances(date).sunny, 1f64);
+    }
+    assert!(!is_festival(14i32));
+}
+
+#[test]
+fn known_chances() {
+    // Summer 7, rolled on Summer 6.
+    let summer: WeatherChances = chances(35i32);
+    assert!((summer.rain - 0.138f64 * 0.1125f64).abs() < 1e-6f64);
+    assert!((summer.storm - 0.138f64 * 0.8875f64).abs() < 1e-6f64);
+    assert!((summer.sunny - 0.862f64).abs() < 1e-6f64);
+    assert_eq!(summer.wind, 0f64);
+
+    // Winter 10.
+    let winter: WeatherChances = chances(94i32);
+    assert!((winter.snow - 0.63f64).abs() < 1e-9f64);
+    assert!((winter.sunny - 0.37f64).abs() < 1e-9f64);
+    assert_eq!(winter.reason, None);
+}
+
+#[test]
+fn chances_add_up() {
+    for date in 1i32..225i32 {
+        let chances: WeatherChances = chances(date);
+        let total: f64 = chances.sunny + chances.rain + chances.storm + chances.snow + chances.wind;
+        assert!((total - 1f64).abs() < 1e-9f64, "{}: {}", date, total);
+    }
+}
+
+#[test]
+fn winter_has_no_rain() {
+    for date in 85i32..113i32 {
+        let chances: WeatherChances = chances(date);
+        assert_eq!(chances.rain, 0f64);
+        assert_eq!(chances.storm, 0f64);
+    }
+}
+
+#[test]
+fn wind_needs_a_dry_yesterday() {
+    // After the fixed rain on Spring 3.
+    assert_eq!(chances(4i32).wind, 0f64);
+    assert!((chances(4i32).sunny - 0.817f64).abs() < 1e-9f64);
+
+    // After the fixed sunny Fall 1, wind isn't ruled out.
+    assert!((chances(58i32).wind - 0.817f64 * 0.6f64).abs() < 1e-9f64);
+
+    // Spring 5 and Fall 5, where the day before rains or storms 18.3% of the time.
+    assert!((chances(5i32).wind - 0.817f64 * 0.2f64 * 0.817f64).abs() < 1e-9f64);
+    assert!((chances(61i32).wind - 0.817f64 * 0.6f64 * 0.817f64).abs() < 1e-9f64);
+    assert!((chances(61i32).storm - 0.183f64 * 0.25f64).abs() < 1e-9f64);
+}